@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use vet::Vet;
+
+struct EvenUsize(usize);
+
+struct OddUsize;
+
+impl Vet for EvenUsize {
+    type Error = OddUsize;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0 % 2 == 0 {
+            Ok(())
+        } else {
+            Err(OddUsize)
+        }
+    }
+}
+
+fn try_for_each(items: &[EvenUsize]) -> Result<(), OddUsize> {
+    items.iter().try_for_each(|t| t.is_valid())
+}
+
+fn manual_loop(items: &[EvenUsize]) -> Result<(), OddUsize> {
+    for t in items {
+        t.is_valid()?;
+    }
+    Ok(())
+}
+
+fn vet_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec");
+    for len in [1_000, 1_000_000] {
+        let items: Vec<EvenUsize> = (0..len).map(|i| EvenUsize(i * 2)).collect();
+        group.bench_with_input(BenchmarkId::new("try_for_each", len), &items, |b, i| {
+            b.iter(|| try_for_each(black_box(i)))
+        });
+        group.bench_with_input(BenchmarkId::new("manual_loop", len), &items, |b, i| {
+            b.iter(|| manual_loop(black_box(i)))
+        });
+        group.bench_with_input(BenchmarkId::new("is_valid", len), &items, |b, i| {
+            b.iter(|| black_box(i).is_valid())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, vet_vec);
+criterion_main!(benches);
@@ -11,8 +11,9 @@
 //! The `Valid<T>` wrapper guarantees that the inner value was successfully
 //! validated and remains immutable as long as it is wrapped.
 //!
-//! Implementations for generic arrays, and for the common standard library
-//! types `Vec<T>` and `Option<T>` are provided.
+//! Implementations for references, slices, generic arrays, tuples, and for the
+//! common standard library types `Vec<T>`, `Option<T>`, and `HashMap<K, V>` are
+//! provided.
 //!
 //! # Examples
 //!
@@ -33,10 +34,10 @@
 //!     type Error = InvalidUsername;
 //!
 //!     fn is_valid(&self) -> Result<(), Self::Error> {
-//!         if self.0.len() < 3 {
+//!         if self.0.chars().count() < 3 {
 //!             return Err(Self::Error::TooShort);
 //!         }
-//!         if self.0.len() > 32 {
+//!         if self.0.chars().count() > 32 {
 //!             return Err(Self::Error::TooLong);
 //!         }
 //!         if self.0.chars().any(|c| !c.is_alphanumeric()) {
@@ -70,15 +71,370 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+/// Validates an expression, propagating the error with `?` on failure.
+///
+/// `vet!(expr)` expands to `Vet::vet(expr)?`, and so may only be used within
+/// functions returning a compatible `Result`.
+///
+/// ```
+/// use vet::{vet, Valid, Vet};
+///
+/// #[derive(Debug)]
+/// struct Even(u32);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Odd;
+///
+/// impl Vet for Even {
+///     type Error = Odd;
+///
+///     fn is_valid(&self) -> Result<(), Self::Error> {
+///         if self.0 % 2 == 0 {
+///             Ok(())
+///         } else {
+///             Err(Odd)
+///         }
+///     }
+/// }
+///
+/// fn pair(a: u32, b: u32) -> Result<(Valid<Even>, Valid<Even>), Odd> {
+///     let a = vet!(Even(a));
+///     let b = vet!(Even(b));
+///     Ok((a, b))
+/// }
+///
+/// assert!(pair(2, 4).is_ok());
+/// assert_eq!(pair(2, 5).unwrap_err(), Odd);
+/// ```
+#[macro_export]
+macro_rules! vet {
+    ($e:expr) => {
+        $crate::Vet::vet($e)?
+    };
+}
+
+/// Projects a named field out of a `Valid` struct, resulting in a `Valid`
+/// reference to the field.
+///
+/// `valid_field!(value.field)` is shorthand for
+/// `value.map_ref(|v| &v.field)`, and is subject to the same trust assumption.
+/// Only real fields may be projected:
+///
+/// ```compile_fail
+/// use vet::{valid_field, Vet};
+///
+/// struct Point {
+///     x: u32,
+/// }
+///
+/// impl Vet for Point {
+///     type Error = ();
+///
+///     fn is_valid(&self) -> Result<(), Self::Error> {
+///         Ok(())
+///     }
+/// }
+///
+/// let point = Point { x: 1 }.vet().unwrap();
+/// let y = valid_field!(point.y);
+/// ```
+#[macro_export]
+macro_rules! valid_field {
+    ($v:ident . $field:tt) => {
+        $crate::Valid::map_ref(&$v, |v| &v.$field)
+    };
+}
+
+/// Implements `Vet` for a single-field numeric newtype, validating that the
+/// field lies within a range.
+///
+/// The error must be a unit struct, which is returned when the value is out of
+/// range.
+///
+/// ```
+/// use vet::{impl_ranged_vet, Vet};
+///
+/// struct Percent(u8);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct OutOfRange;
+///
+/// impl_ranged_vet!(Percent, 0..=100, OutOfRange);
+///
+/// assert!(Percent(42).is_valid().is_ok());
+/// assert_eq!(Percent(101).is_valid(), Err(OutOfRange));
+/// ```
+#[macro_export]
+macro_rules! impl_ranged_vet {
+    ($ty:ty, $range:expr, $err:path) => {
+        impl $crate::Vet for $ty {
+            type Error = $err;
+
+            fn is_valid(&self) -> ::core::result::Result<(), Self::Error> {
+                if ($range).contains(&self.0) {
+                    ::core::result::Result::Ok(())
+                } else {
+                    ::core::result::Result::Err($err)
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an expression is valid, resulting in the `Valid` wrapper.
+///
+/// `assert_valid!(expr)` calls `Vet::vet(expr)` and panics with the `Debug`
+/// representation of the error if validation fails, so the error type must
+/// implement `Debug`. This is intended for tests.
+///
+/// ```
+/// use vet::{assert_valid, Vet};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Even(u32);
+///
+/// #[derive(Debug)]
+/// struct Odd;
+///
+/// impl Vet for Even {
+///     type Error = Odd;
+///
+///     fn is_valid(&self) -> Result<(), Self::Error> {
+///         if self.0 % 2 == 0 {
+///             Ok(())
+///         } else {
+///             Err(Odd)
+///         }
+///     }
+/// }
+///
+/// let even = assert_valid!(Even(4));
+/// assert_eq!(*even, Even(4));
+/// ```
+#[macro_export]
+macro_rules! assert_valid {
+    ($e:expr $(,)?) => {
+        match $crate::Vet::vet($e) {
+            Ok(valid) => valid,
+            Err(e) => panic!(
+                "assertion failed: `{}` is not valid: {:?}",
+                stringify!($e),
+                e
+            ),
+        }
+    };
+}
+
 /// A wrapper around a validated instance
+///
+/// `Valid<T>` is guaranteed to have the same memory layout as `T`. Comparisons
+/// and ordering are those of the wrapped value, so sorting `Valid<T>` values
+/// orders them as their unwrapped values would be.
+///
+/// Validity is a correctness guarantee, not a safety invariant. A wrapped
+/// value with interior mutability may change after validation, and a type
+/// incorrectly setting `Vet::IS_INFALLIBLE` may be wrapped without passing
+/// validation, so unsafe code must not rely on validity for memory safety.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
 pub struct Valid<T>(T);
 
 impl<T> Valid<T> {
+    /// Reinterprets a reference to a value as a reference to a `Valid` value,
+    /// without validating it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the value is valid, and remains valid for the
+    /// lifetime of the returned reference. The cast itself is sound because
+    /// `Valid<T>` is `#[repr(transparent)]`.
+    pub unsafe fn from_ref(value: &T) -> &Valid<T> {
+        // SAFETY: `Valid<T>` is `#[repr(transparent)]` over `T`.
+        unsafe { &*(value as *const T as *const Valid<T>) }
+    }
+
+    /// Combines two validated parts into a validated whole, without
+    /// validating the result.
+    ///
+    /// This is a trusted constructor, like `map_ref`. The combination is
+    /// trusted to preserve validity, and it is the caller's responsibility to
+    /// ensure that any `T` built from valid parts by `combine` is itself valid.
+    pub fn from_validated_parts<A, B, F>(a: Valid<A>, b: Valid<B>, combine: F) -> Valid<T>
+    where
+        F: FnOnce(A, B) -> T,
+    {
+        Valid(combine(a.0, b.0))
+    }
+
     /// Consumes the `Valid` wrapper, returning the wrapped value.
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Mutably borrows the wrapped value without any re-validation guard.
+    ///
+    /// Prefer `scope`, which re-validates the value after mutation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the wrapped value is still valid when the
+    /// borrow ends. Code receiving a `Valid<T>` is entitled to rely on its
+    /// validity for correctness, so leaving an invalid value in the wrapper
+    /// breaks the contract of this type.
+    pub unsafe fn dangerous_get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Borrows the wrapped value as a pinned shared reference.
+    ///
+    /// This requires `T: Unpin`, since the wrapper itself may later be moved,
+    /// which the pinning contract forbids for types that are not `Unpin`.
+    pub fn as_pinned(&self) -> core::pin::Pin<&T>
+    where
+        T: Unpin,
+    {
+        core::pin::Pin::new(&self.0)
+    }
+
+    /// Keeps the `Valid` wrapper only if an additional predicate holds,
+    /// resulting in the given error otherwise.
+    pub fn ensure<E, F: FnOnce(&T) -> bool>(self, predicate: F, err: E) -> Result<Valid<T>, E> {
+        if predicate(&self.0) {
+            Ok(self)
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Projects a reference into the wrapped value.
+    ///
+    /// The projection is trusted to be valid, since it borrows from a value
+    /// that has already passed validation. It is the caller's responsibility
+    /// to only project into parts whose validity is implied by the whole.
+    pub fn map_ref<U: ?Sized, F: FnOnce(&T) -> &U>(&self, f: F) -> Valid<&U> {
+        Valid(f(&self.0))
+    }
+
+    /// Derives a new owned value from the wrapped value and validates it,
+    /// leaving this wrapper usable.
+    pub fn project<U: Vet, F: FnOnce(&T) -> U>(&self, f: F) -> Result<Valid<U>, U::Error> {
+        f(&self.0).vet()
+    }
+
+    /// Tests this value and another for equality with a custom predicate.
+    pub fn inner_eq_by<F: FnOnce(&T, &T) -> bool>(&self, other: &Valid<T>, eq: F) -> bool {
+        eq(&self.0, &other.0)
+    }
+
+    /// Moves the wrapped value into an `Rc` for cheap sharing.
+    ///
+    /// `Rc` only permits shared access to its value, so the validity of the
+    /// value is preserved.
+    #[cfg(feature = "alloc")]
+    pub fn into_shared(self) -> Valid<alloc::rc::Rc<T>> {
+        Valid(alloc::rc::Rc::new(self.0))
+    }
+
+    /// Returns an iterator over exactly one reference to the wrapped value.
+    ///
+    /// This is named so as not to shadow `iter` methods of the wrapped value,
+    /// such as `Vec::iter`, which remain reachable through `Deref`.
+    pub fn iter_once(&self) -> core::iter::Once<&T> {
+        core::iter::once(&self.0)
+    }
+
+    /// Moves the wrapped value into an `Arc` for cheap sharing across
+    /// threads.
+    ///
+    /// While wrapped, the value can only be accessed immutably. Note that
+    /// once unwrapped with `into_inner`, `Arc::get_mut` may permit mutation of
+    /// a uniquely owned value, so an `Arc` taken out of the wrapper should no
+    /// longer be assumed valid.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub fn into_shared_sync(self) -> Valid<alloc::sync::Arc<T>> {
+        Valid(alloc::sync::Arc::new(self.0))
+    }
+
+    /// Compares this value with another `Valid` value by a shared key.
+    pub fn compare_by<U, K, F, G>(&self, other: &Valid<U>, f: F, g: G) -> core::cmp::Ordering
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+        G: Fn(&U) -> K,
+    {
+        f(&self.0).cmp(&g(&other.0))
+    }
+}
+
+impl<T: Vet> Valid<T> {
+    /// Captures a value, deferring its validation until the resulting closure
+    /// is called.
+    pub fn into_validator(value: T) -> impl FnOnce() -> Result<Valid<T>, T::Error> {
+        move || value.vet()
+    }
+
+    /// Re-runs validation on the wrapped value without consuming the wrapper.
+    ///
+    /// This is useful for defensive programming when the wrapped type has
+    /// interior mutability and may have changed since it was validated.
+    pub fn recheck(&self) -> Result<(), T::Error> {
+        self.0.is_valid()
+    }
+
+    /// Consumes the wrapper and re-validates the wrapped value, resulting in
+    /// the wrapper again if it is still valid.
+    ///
+    /// This is useful at trust boundaries, such as after deserialization which
+    /// bypassed validation.
+    pub fn verify_invariant(self) -> Result<Valid<T>, T::Error> {
+        self.0.is_valid()?;
+        Ok(self)
+    }
+
+    /// Temporarily unwraps the value for mutation within a closure, then
+    /// re-validates it.
+    ///
+    /// Results in the rewrapped value and the closure's result if the value is
+    /// still valid, or the unwrapped value and the validation error otherwise.
+    pub fn scope<R, F>(self, f: F) -> Result<(Valid<T>, R), (T, T::Error)>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut value = self.0;
+        let result = f(&mut value);
+        match value.is_valid() {
+            Ok(()) => Ok((Valid(value), result)),
+            Err(e) => Err((value, e)),
+        }
+    }
+
+    /// Applies a mutation to a clone of the wrapped value, resulting in the
+    /// validated clone if it is still valid.
+    ///
+    /// The original wrapper is left untouched.
+    pub fn with<F: FnOnce(&mut T)>(&self, f: F) -> Result<Valid<T>, T::Error>
+    where
+        T: Clone,
+    {
+        let mut value = self.0.clone();
+        f(&mut value);
+        value.vet()
+    }
+}
+
+impl<T: Default + Vet> Valid<T> {
+    /// Takes the wrapped value, leaving a validated default in its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the default value of `T` is not valid.
+    pub fn take(&mut self) -> Valid<T> {
+        let default = T::default();
+        if default.is_valid().is_err() {
+            panic!("default value failed validation");
+        }
+        Valid(core::mem::replace(&mut self.0, default))
+    }
 }
 
 impl<T> core::ops::Deref for Valid<T> {
@@ -89,14 +445,219 @@ impl<T> core::ops::Deref for Valid<T> {
     }
 }
 
+/// A validated value for use as a map key
+///
+/// `ValidKey<T>` can only be constructed from a `Valid<T>`, and hashes and
+/// compares by the wrapped value. It implements `Borrow<T>`, so maps keyed by
+/// `ValidKey<T>` may be queried with an unvalidated `&T`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ValidKey<T>(Valid<T>);
+
+impl<T> ValidKey<T> {
+    /// Consumes the key, resulting in the validated value.
+    pub fn into_inner(self) -> Valid<T> {
+        self.0
+    }
+}
+
+impl<T> From<Valid<T>> for ValidKey<T> {
+    fn from(valid: Valid<T>) -> Self {
+        ValidKey(valid)
+    }
+}
+
+impl<T> core::ops::Deref for ValidKey<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::borrow::Borrow<T> for ValidKey<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+/// The number of candidate values generated by `Valid<T>`'s `Arbitrary`
+/// implementation before giving up
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_ATTEMPTS: usize = 16;
+
+/// Generates arbitrary `T` values until one passes validation.
+///
+/// To avoid looping forever on types which are rarely valid, generation gives
+/// up after a bounded number of attempts and returns
+/// `arbitrary::Error::IncorrectFormat`, which fuzzers treat as a rejected
+/// input.
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Valid<T>
+where
+    T: arbitrary::Arbitrary<'a> + Vet,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        for _ in 0..ARBITRARY_ATTEMPTS {
+            if let Ok(valid) = T::arbitrary(u)?.vet() {
+                return Ok(valid);
+            }
+        }
+        Err(arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// A value which was validated, but may have been modified since
+///
+/// Unlike `Valid<T>`, `Checked<T>` allows mutable access to the wrapped value,
+/// marking it dirty until it is revalidated. This suits workflows where a
+/// validated value is edited before being committed as a `Valid<T>` again.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Checked<T> {
+    value: T,
+    dirty: bool,
+}
+
+impl<T> Checked<T> {
+    /// Wraps an unvalidated value, which is dirty until revalidated.
+    pub fn new(value: T) -> Self {
+        Checked { value, dirty: true }
+    }
+
+    /// Returns a mutable reference to the wrapped value, marking it dirty.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.dirty = true;
+        &mut self.value
+    }
+
+    /// Returns whether the wrapped value may have changed since it was last
+    /// validated.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Consumes the wrapper, resulting in the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Vet> Checked<T> {
+    /// Validates the wrapped value if it is dirty, marking it clean if it is
+    /// valid.
+    pub fn revalidate(&mut self) -> Result<(), T::Error> {
+        if self.dirty {
+            self.value.is_valid()?;
+            self.dirty = false;
+        }
+        Ok(())
+    }
+
+    /// Consumes the wrapper, resulting in a `Valid` wrapper after validating
+    /// the wrapped value if it is dirty.
+    pub fn into_valid(mut self) -> Result<Valid<T>, T::Error> {
+        self.revalidate()?;
+        Ok(Valid(self.value))
+    }
+}
+
+impl<T> From<Valid<T>> for Checked<T> {
+    fn from(valid: Valid<T>) -> Self {
+        Checked {
+            value: valid.0,
+            dirty: false,
+        }
+    }
+}
+
+impl<T> core::ops::Deref for Checked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Serializes the wrapped value transparently.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Valid<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes and validates the wrapped value.
+///
+/// Validation failure is reported as a custom deserialization error including
+/// the validation error's `Display` representation.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: serde::Deserialize<'de> + Vet,
+    T::Error: core::fmt::Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer)?
+            .vet()
+            .map_err(|e| serde::de::Error::custom(format_args!("validation failed: {}", e)))
+    }
+}
+
 /// An interface for arbitrary type validation
 pub trait Vet {
     /// The error returned by failed validation
     type Error;
 
+    /// Whether `is_valid` can never fail for this type.
+    ///
+    /// Generic code may skip validation entirely for types setting this to
+    /// `true`. Setting it incorrectly is a logic error, but not undefined
+    /// behavior.
+    const IS_INFALLIBLE: bool = false;
+
     /// Executes arbitrary validation logic on this instance.
     fn is_valid(&self) -> Result<(), Self::Error>;
 
+    /// Estimates the cost of validating this instance.
+    ///
+    /// Schedulers may use this to decide whether validation is worth running
+    /// in parallel. By default, validation is assumed to be cheap.
+    fn validation_cost(&self) -> ValidationCost {
+        ValidationCost::Cheap
+    }
+
+    /// Executes arbitrary validation logic on this instance, grading the
+    /// severity of any failure.
+    ///
+    /// By default, all failures of `is_valid` are graded as errors. Types may
+    /// override this to report non-blocking issues as warnings, in which case
+    /// `is_valid` should still succeed when only warnings are reported.
+    fn is_valid_graded(&self) -> Result<(), (Severity, Self::Error)> {
+        self.is_valid().map_err(|e| (Severity::Error, e))
+    }
+
+    /// Executes arbitrary validation logic on this instance, collecting every
+    /// failure along with its severity.
+    ///
+    /// By default, this results in the single failure of `is_valid_graded`,
+    /// if any. Types may override this to report multiple failures.
+    #[cfg(feature = "alloc")]
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        self.is_valid_graded().err().into_iter().collect()
+    }
+
+    /// Executes arbitrary validation logic on this instance, recording where
+    /// validation failed.
+    ///
+    /// On failure, `path` is left holding the segments leading from this
+    /// instance to the invalid value. Types containing other `Vet` types may
+    /// override this to push a segment before validating each part, and pop it
+    /// once the part is valid. By default, no segments are recorded.
+    #[cfg(feature = "alloc")]
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        let _ = path;
+        self.is_valid()
+    }
+
     /// Validates this instance and results in a wrapped instance if successful.
     fn vet(self) -> Result<Valid<Self>, Self::Error>
     where
@@ -107,41 +668,2091 @@ pub trait Vet {
             Err(e) => Err(e),
         }
     }
-}
 
-impl<T: Vet, const N: usize> Vet for [T; N] {
-    type Error = T::Error;
+    /// Validates this instance, then checks that it equals an expected value,
+    /// such as for confirmation fields.
+    fn vet_eq(self, expected: &Self) -> Result<Valid<Self>, VetEqError<Self::Error>>
+    where
+        Self: PartialEq + Sized,
+    {
+        self.is_valid().map_err(VetEqError::Invalid)?;
+        if self == *expected {
+            Ok(Valid(self))
+        } else {
+            Err(VetEqError::NotEqual)
+        }
+    }
 
-    fn is_valid(&self) -> Result<(), Self::Error> {
-        self.iter().try_for_each(|i| i.is_valid())
+    /// Validates this instance, resulting in the wrapped instance or panicking
+    /// with `msg` and the `Debug` representation of the error.
+    ///
+    /// Like `Result::expect`, this is intended for tests and prototypes.
+    ///
+    /// ```
+    /// use vet::Vet;
+    ///
+    /// struct Even(u32);
+    ///
+    /// #[derive(Debug)]
+    /// struct Odd;
+    ///
+    /// impl Vet for Even {
+    ///     type Error = Odd;
+    ///
+    ///     fn is_valid(&self) -> Result<(), Self::Error> {
+    ///         if self.0 % 2 == 0 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(Odd)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let even = Even(4).vet_expect("four is even");
+    /// assert_eq!(even.0, 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this instance fails validation.
+    fn vet_expect(self, msg: &str) -> Valid<Self>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Debug,
+    {
+        match self.is_valid() {
+            Ok(()) => Valid(self),
+            Err(e) => panic!("{}: {:?}", msg, e),
+        }
     }
-}
 
-impl<T: Vet> Vet for Option<T> {
-    type Error = T::Error;
+    /// Validates this instance by reference and results in a wrapped reference
+    /// if successful.
+    ///
+    /// Unlike `vet`, this does not require `Self: Sized`, so unsized types
+    /// such as `[T]`, or newtypes over `str`, may be validated in place.
+    fn vet_ref(&self) -> Result<Valid<&Self>, Self::Error> {
+        self.is_valid()?;
+        Ok(Valid(self))
+    }
 
-    fn is_valid(&self) -> Result<(), Self::Error> {
-        match self {
-            Some(o) => o.is_valid(),
-            None => Ok(()),
+    /// Validates this instance in the given mode, collecting errors on failure.
+    ///
+    /// Whether this instance is valid is always decided by `is_valid`, so both
+    /// modes agree, and only the errors collected on failure differ. In
+    /// `VetMode::FirstError`, only the error of `is_valid` is returned, as with
+    /// `vet`. In `VetMode::AllErrors`, every failure graded as an error by
+    /// `is_valid_detailed` is collected, falling back to the error of
+    /// `is_valid` if there are none.
+    #[cfg(feature = "alloc")]
+    fn vet_mode(self, mode: VetMode) -> Result<Valid<Self>, alloc::vec::Vec<Self::Error>>
+    where
+        Self: Sized,
+    {
+        let first = match self.is_valid() {
+            Ok(()) => return Ok(Valid(self)),
+            Err(e) => e,
+        };
+        let mut errors = alloc::vec::Vec::new();
+        if mode == VetMode::AllErrors {
+            errors.extend(
+                self.is_valid_detailed()
+                    .into_iter()
+                    .filter(|(severity, _)| *severity == Severity::Error)
+                    .map(|(_, e)| e),
+            );
+        }
+        if errors.is_empty() {
+            errors.push(first);
+        }
+        Err(errors)
+    }
+
+    /// Validates this instance on a worker thread, giving up if validation
+    /// takes longer than `duration`.
+    ///
+    /// This spawns a thread for every call, so the instance and its error must
+    /// be `Send + 'static`. On timeout the instance is lost, and the worker
+    /// thread is left to finish validation in the background, since threads
+    /// cannot be cancelled. A panic on the worker thread is propagated.
+    #[cfg(feature = "std")]
+    fn vet_timeout(
+        self,
+        duration: std::time::Duration,
+    ) -> Result<Valid<Self>, TimeoutOrError<Self::Error>>
+    where
+        Self: Send + Sized + 'static,
+        Self::Error: Send + 'static,
+    {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let worker = std::thread::spawn(move || {
+            let result = self.vet();
+            let _ = sender.send(result);
+        });
+        match receiver.recv_timeout(duration) {
+            Ok(result) => result.map_err(TimeoutOrError::Invalid),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => Err(TimeoutOrError::Timeout),
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => match worker.join() {
+                Ok(()) => unreachable!("worker exited without sending a result"),
+                Err(payload) => std::panic::resume_unwind(payload),
+            },
+        }
+    }
+
+    /// Validates this instance, pairing the error with its HTTP-style status
+    /// code on failure.
+    #[cfg(feature = "status")]
+    fn vet_status(self) -> Result<Valid<Self>, (u16, Self::Error)>
+    where
+        Self: Sized,
+        Self::Error: VetStatus,
+    {
+        self.vet().map_err(|e| (e.status_code(), e))
+    }
+
+    /// Validates this instance, capturing its `Debug` representation
+    /// alongside the error on failure.
+    #[cfg(feature = "alloc")]
+    fn vet_debug_err(self) -> Result<Valid<Self>, DebugError<Self::Error>>
+    where
+        Self: core::fmt::Debug + Sized,
+    {
+        match self.is_valid() {
+            Ok(()) => Ok(Valid(self)),
+            Err(error) => Err(DebugError {
+                error,
+                debug: alloc::format!("{:?}", self),
+            }),
+        }
+    }
+
+    /// Validates this instance, collecting failures graded as warnings.
+    ///
+    /// Results in the wrapped instance and any warnings reported by
+    /// `is_valid_detailed` if `is_valid` succeeds, or the first failure
+    /// otherwise. A failure graded as an error by `is_valid_detailed` is also
+    /// returned, even if `is_valid` succeeded.
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    fn vet_with_warnings(self) -> Result<(Valid<Self>, alloc::vec::Vec<Self::Error>), Self::Error>
+    where
+        Self: Sized,
+    {
+        self.is_valid()?;
+        let mut warnings = alloc::vec::Vec::new();
+        for (severity, e) in self.is_valid_detailed() {
+            match severity {
+                Severity::Warning => warnings.push(e),
+                Severity::Error => return Err(e),
+            }
+        }
+        Ok((Valid(self), warnings))
+    }
+
+    /// Validates this instance, then runs additional checks in order,
+    /// stopping at the first failure.
+    fn vet_all_of<const N: usize>(
+        self,
+        checks: [Check<Self>; N],
+    ) -> Result<Valid<Self>, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.is_valid()?;
+        for check in checks {
+            check(&self)?;
+        }
+        Ok(Valid(self))
+    }
+
+    /// Validates this instance, then runs alternative checks in order,
+    /// stopping at the first which passes.
+    ///
+    /// If this instance fails `is_valid`, that error is returned without
+    /// running any of the checks. If every check fails, all of their errors
+    /// are returned.
+    fn vet_any_of<const N: usize>(
+        self,
+        checks: [Check<Self>; N],
+    ) -> Result<Valid<Self>, AnyOfError<Self::Error, N>>
+    where
+        Self: Sized,
+    {
+        self.is_valid().map_err(AnyOfError::Invalid)?;
+        let mut errors: [Option<Self::Error>; N] = core::array::from_fn(|_| None);
+        for (check, error) in checks.iter().zip(&mut errors) {
+            match check(&self) {
+                Ok(()) => return Ok(Valid(self)),
+                Err(e) => *error = Some(e),
+            }
+        }
+        Err(AnyOfError::NoneMatched(errors.map(Option::unwrap)))
+    }
+
+    /// Validates this instance which can never fail validation, resulting in
+    /// a wrapped instance.
+    fn vet_infallible(self) -> Valid<Self>
+    where
+        Self: Vet<Error = core::convert::Infallible> + Sized,
+    {
+        match self.is_valid() {
+            Ok(()) => Valid(self),
+            Err(e) => match e {},
+        }
+    }
+
+    /// Validates this instance and results in an `Outcome`, which retains the
+    /// instance alongside the error if validation failed.
+    fn vet_outcome(self) -> Outcome<Self>
+    where
+        Self: Sized,
+    {
+        match self.is_valid() {
+            Ok(()) => Outcome::Valid(Valid(self)),
+            Err(e) => Outcome::Invalid(self, e),
         }
     }
 }
 
-impl<T: Vet> Valid<Option<T>> {
-    pub fn transpose(self) -> Option<Valid<T>> {
+/// A function performing an additional validation check on a value
+pub type Check<T> = fn(&T) -> Result<(), <T as Vet>::Error>;
+
+/// A segment of the path to a value within a nested structure
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// A named field.
+    Field(&'static str),
+    /// An index into a sequence.
+    Index(usize),
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Valid(Some(o)) => Some(Valid(o)),
-            Valid(None) => None,
+            PathSegment::Field(name) => f.write_str(name),
+            PathSegment::Index(index) => write!(f, "{}", index),
         }
     }
 }
 
+/// Whether validation stops at the first error or collects every error
 #[cfg(feature = "alloc")]
-impl<T: Vet> Vet for alloc::vec::Vec<T> {
-    type Error = T::Error;
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VetMode {
+    /// Stop at the first error.
+    FirstError,
+    /// Collect every error.
+    AllErrors,
+}
+
+/// Conversion into a type which is then validated
+///
+/// This is implemented for every type.
+pub trait VetInto: Sized {
+    /// Converts this value into `U`, then validates it.
+    fn vet_into<U: Vet>(self) -> Result<Valid<U>, U::Error>
+    where
+        Self: Into<U>,
+    {
+        self.into().vet()
+    }
+}
+
+impl<T> VetInto for T {}
+
+/// An estimate of the cost of validating an instance
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ValidationCost {
+    /// Validation is cheap, and is best run inline.
+    #[default]
+    Cheap,
+    /// Validation is expensive, and may benefit from running in parallel.
+    Expensive,
+}
+
+/// A validation error which maps to an HTTP-style status code
+#[cfg(feature = "status")]
+pub trait VetStatus {
+    /// Returns the status code describing this error, typically in the
+    /// 400-series.
+    fn status_code(&self) -> u16;
+}
+
+/// The error returned by `Vet::vet_eq`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VetEqError<E> {
+    /// The instance failed validation.
+    Invalid(E),
+    /// The instance did not equal the expected value.
+    NotEqual,
+}
+
+/// The error returned by `Vet::vet_any_of`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnyOfError<E, const N: usize> {
+    /// The instance failed its own validation.
+    Invalid(E),
+    /// Every alternative check failed.
+    NoneMatched([E; N]),
+}
+
+/// The error returned by `Vet::vet_timeout`
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimeoutOrError<E> {
+    /// Validation did not finish in time.
+    Timeout,
+    /// The instance failed validation.
+    Invalid(E),
+}
+
+/// The severity of a validation failure
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The failure should be surfaced, but does not block use of the value.
+    Warning,
+    /// The failure blocks use of the value.
+    Error,
+}
+
+/// A validation error accompanied by the `Debug` representation of the
+/// invalid value
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DebugError<E> {
+    /// The validation error
+    pub error: E,
+    /// The `Debug` representation of the invalid value
+    pub debug: alloc::string::String,
+}
+
+/// The outcome of validating an instance
+#[derive(Debug, PartialEq)]
+pub enum Outcome<T: Vet> {
+    /// The instance was successfully validated.
+    Valid(Valid<T>),
+    /// The instance failed validation.
+    Invalid(T, T::Error),
+}
+
+/// Vacuously valid, as no instance can exist.
+impl Vet for core::convert::Infallible {
+    type Error = core::convert::Infallible;
+
+    const IS_INFALLIBLE: bool = true;
 
     fn is_valid(&self) -> Result<(), Self::Error> {
-        self.iter().try_for_each(|t| t.is_valid())
+        match *self {}
     }
 }
+
+/// A transparent wrapper around an inner value
+///
+/// Implementing `Transparent` provides a `Vet` implementation which forwards
+/// validation to the inner value, so newtypes need not implement `Vet` by
+/// hand. A type may implement either `Transparent` or `Vet`, but not both.
+pub trait Transparent {
+    /// The wrapped type
+    type Inner: ?Sized;
+
+    /// Borrows the inner value.
+    fn inner(&self) -> &Self::Inner;
+}
+
+impl<T> Vet for T
+where
+    T: Transparent + ?Sized,
+    T::Inner: Vet,
+{
+    type Error = <T::Inner as Vet>::Error;
+
+    const IS_INFALLIBLE: bool = <T::Inner as Vet>::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.inner().is_valid()
+    }
+
+    fn validation_cost(&self) -> ValidationCost {
+        self.inner().validation_cost()
+    }
+
+    fn is_valid_graded(&self) -> Result<(), (Severity, Self::Error)> {
+        self.inner().is_valid_graded()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        self.inner().is_valid_detailed()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        self.inner().is_valid_at(path)
+    }
+}
+
+impl<T: ?Sized> Transparent for &T {
+    type Inner = T;
+
+    fn inner(&self) -> &Self::Inner {
+        self
+    }
+}
+
+impl<T> Transparent for core::num::Wrapping<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.0
+    }
+}
+
+impl<T> Transparent for core::cmp::Reverse<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Transparent for alloc::boxed::Box<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &Self::Inner {
+        self
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: ?Sized> Transparent for alloc::sync::Arc<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &Self::Inner {
+        self
+    }
+}
+
+impl<T: Vet> Vet for [T] {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        for t in self {
+            t.is_valid()?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        self.iter().flat_map(T::is_valid_detailed).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        for (i, t) in self.iter().enumerate() {
+            path.push(PathSegment::Index(i));
+            t.is_valid_at(path)?;
+            path.pop();
+        }
+        Ok(())
+    }
+}
+
+/// Validation methods specific to slices
+pub trait VetSlice<T: Vet> {
+    /// Validates every element in reverse order, resulting in the error of the
+    /// last invalid element on failure.
+    ///
+    /// This is useful for data where later elements are more likely to be
+    /// invalid, such as append-only logs, or where the last failure is the
+    /// most relevant to report.
+    fn vet_rev(&self) -> Result<Valid<&[T]>, T::Error>;
+}
+
+impl<T: Vet> VetSlice<T> for [T] {
+    fn vet_rev(&self) -> Result<Valid<&[T]>, T::Error> {
+        for t in self.iter().rev() {
+            t.is_valid()?;
+        }
+        Ok(Valid(self))
+    }
+}
+
+impl<T: Vet, const N: usize> Vet for [T; N] {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.iter().try_for_each(|i| i.is_valid())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        self.as_slice().is_valid_detailed()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        self.as_slice().is_valid_at(path)
+    }
+}
+
+impl<T: Vet> Vet for Option<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self {
+            Some(o) => o.is_valid(),
+            None => Ok(()),
+        }
+    }
+
+    fn is_valid_graded(&self) -> Result<(), (Severity, Self::Error)> {
+        match self {
+            Some(o) => o.is_valid_graded(),
+            None => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        match self {
+            Some(o) => o.is_valid_detailed(),
+            None => alloc::vec::Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        match self {
+            Some(o) => o.is_valid_at(path),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validates the start of the range.
+impl<T: Vet> Vet for core::ops::RangeFrom<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.start.is_valid()
+    }
+}
+
+/// Validates the end of the range.
+impl<T: Vet> Vet for core::ops::RangeTo<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.end.is_valid()
+    }
+}
+
+/// Validates the bounding value of `Included` and `Excluded` bounds.
+///
+/// `Unbounded` is valid, like `None`.
+impl<T: Vet> Vet for core::ops::Bound<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self {
+            core::ops::Bound::Included(t) | core::ops::Bound::Excluded(t) => t.is_valid(),
+            core::ops::Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+/// Validates whichever of the `Continue` or `Break` values is present.
+impl<B: Vet, C: Vet<Error = B::Error>> Vet for core::ops::ControlFlow<B, C> {
+    type Error = B::Error;
+
+    const IS_INFALLIBLE: bool = B::IS_INFALLIBLE && C::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self {
+            core::ops::ControlFlow::Continue(c) => c.is_valid(),
+            core::ops::ControlFlow::Break(b) => b.is_valid(),
+        }
+    }
+}
+
+/// Validates a copy of the contained value.
+///
+/// Since the cell may be modified through a shared reference after
+/// validation, a `Valid<Cell<T>>` only guarantees that the value was valid
+/// when it was validated.
+impl<T: Copy + Vet> Vet for core::cell::Cell<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.get().is_valid()
+    }
+}
+
+/// Validates the contained value if the cell is initialized.
+///
+/// An uninitialized cell is valid, like `None`. Since the cell may be
+/// initialized after validation, a `Valid<OnceCell<T>>` only guarantees that
+/// the value is valid if it was present when validated.
+impl<T: Vet> Vet for core::cell::OnceCell<T> {
+    type Error = T::Error;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self.get() {
+            Some(t) => t.is_valid(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validates the contained value if the lock is initialized.
+///
+/// An uninitialized lock is valid, like `None`. Since the lock may be
+/// initialized after validation, a `Valid<OnceLock<T>>` only guarantees that
+/// the value is valid if it was present when validated.
+#[cfg(feature = "std")]
+impl<T: Vet> Vet for std::sync::OnceLock<T> {
+    type Error = T::Error;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self.get() {
+            Some(t) => t.is_valid(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Vet> Valid<Option<T>> {
+    pub fn transpose(self) -> Option<Valid<T>> {
+        match self {
+            Valid(Some(o)) => Some(Valid(o)),
+            Valid(None) => None,
+        }
+    }
+}
+
+macro_rules! impl_tuple_vet {
+    ($error:ident; $($t:ident $i:tt),+) => {
+        /// The error returned by failed validation of a tuple, identifying the
+        /// first invalid member
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum $error<$($t),+> {
+            $(
+                #[doc = concat!("The member at index ", stringify!($i), " failed validation.")]
+                $t($t),
+            )+
+        }
+
+        /// Validates each member in order, stopping at the first error.
+        ///
+        /// Since members may have different error types, the error identifies
+        /// the member which failed. Members sharing a type are better validated
+        /// as an array, which results in the member's error type directly.
+        impl<$($t: Vet),+> Vet for ($($t,)+) {
+            type Error = $error<$($t::Error),+>;
+
+            const IS_INFALLIBLE: bool = $($t::IS_INFALLIBLE)&&+;
+
+            fn is_valid(&self) -> Result<(), Self::Error> {
+                $(self.$i.is_valid().map_err($error::$t)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple_vet!(Tuple2Error; A 0, B 1);
+impl_tuple_vet!(Tuple3Error; A 0, B 1, C 2);
+impl_tuple_vet!(Tuple4Error; A 0, B 1, C 2, D 3);
+impl_tuple_vet!(Tuple5Error; A 0, B 1, C 2, D 3, E 4);
+impl_tuple_vet!(Tuple6Error; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Conversion of an optional `Valid` value into a `Valid` option
+pub trait FlattenOption<T> {
+    /// Wraps the whole option as valid, the inverse of
+    /// `Valid<Option<T>>::transpose`.
+    fn flatten_option(self) -> Valid<Option<T>>;
+}
+
+impl<T: Vet> FlattenOption<T> for Option<Valid<T>> {
+    fn flatten_option(self) -> Valid<Option<T>> {
+        Valid(self.map(Valid::into_inner))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> Vet for alloc::vec::Vec<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        // `benches/vet.rs` found no consistent difference between this and a
+        // plain loop, at either 1k or 1M elements.
+        self.iter().try_for_each(|t| t.is_valid())
+    }
+
+    fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+        self.as_slice().is_valid_detailed()
+    }
+
+    fn is_valid_at(&self, path: &mut alloc::vec::Vec<PathSegment>) -> Result<(), Self::Error> {
+        self.as_slice().is_valid_at(path)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Valid<alloc::boxed::Box<T>> {
+    /// Consumes the wrapper, resulting in a raw pointer to the validated
+    /// value, such as for passing across an FFI boundary.
+    ///
+    /// The caller becomes responsible for the memory, which may be released
+    /// by converting the pointer back with `Valid::from_raw`.
+    pub fn into_raw(self) -> *mut T {
+        alloc::boxed::Box::into_raw(self.0)
+    }
+
+    /// Reconstructs a validated box from a raw pointer, without validating
+    /// the value.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must satisfy the requirements of `Box::from_raw`, which
+    /// are met by a pointer previously returned from `Valid::into_raw` that
+    /// has not been converted back already. The value must also still be
+    /// valid, so it must not have been mutated into an invalid state through
+    /// the pointer.
+    pub unsafe fn from_raw(ptr: *mut T) -> Valid<alloc::boxed::Box<T>> {
+        // SAFETY: The caller upholds the contract of `Box::from_raw`.
+        Valid(unsafe { alloc::boxed::Box::from_raw(ptr) })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Valid<alloc::vec::Vec<T>> {
+    /// Maps each element to a new value, validating each mapped value and
+    /// collecting them into a validated vector.
+    pub fn map_each<U: Vet, F: Fn(&T) -> U>(
+        &self,
+        f: F,
+    ) -> Result<Valid<alloc::vec::Vec<U>>, U::Error> {
+        vet_collect(self.0.iter().map(f))
+    }
+
+    /// Checks an index against the length of the validated vector, resulting
+    /// in a `ValidIndex` for panic-free access if it is in bounds.
+    pub fn checked_index(&self, i: usize) -> Option<ValidIndex<'_, T>> {
+        if i < self.0.len() {
+            Some(ValidIndex {
+                slice: &self.0,
+                index: i,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// An index guaranteed to be in bounds of a validated collection
+#[derive(Debug)]
+pub struct ValidIndex<'a, T> {
+    slice: &'a [T],
+    index: usize,
+}
+
+impl<'a, T> ValidIndex<'a, T> {
+    /// Returns the checked index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the indexed element.
+    pub fn get(&self) -> &'a T {
+        // The collection is borrowed for `'a`, so the bounds check made when
+        // this index was created still holds.
+        &self.slice[self.index]
+    }
+}
+
+impl<T> Clone for ValidIndex<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ValidIndex<'_, T> {}
+
+/// Validates every element of the heap in arbitrary order.
+#[cfg(feature = "alloc")]
+impl<T: Vet> Vet for alloc::collections::BinaryHeap<T> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        for t in self {
+            t.is_valid()?;
+        }
+        Ok(())
+    }
+}
+
+/// A validation error with its type erased
+#[cfg(feature = "alloc")]
+pub type BoxedVetError = alloc::boxed::Box<dyn core::fmt::Debug>;
+
+/// An object-safe form of `Vet`, with the error type erased
+///
+/// `ErasedVet` is implemented for every `Vet` type whose error type is
+/// `Debug + 'static`, allowing values of different types to be validated
+/// through trait objects.
+#[cfg(feature = "alloc")]
+pub trait ErasedVet {
+    /// Executes arbitrary validation logic on this instance, boxing the error.
+    fn is_valid_erased(&self) -> Result<(), BoxedVetError>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ErasedVet for T
+where
+    T: Vet + ?Sized,
+    T::Error: core::fmt::Debug + 'static,
+{
+    fn is_valid_erased(&self) -> Result<(), BoxedVetError> {
+        self.is_valid()
+            .map_err(|e| alloc::boxed::Box::new(e) as BoxedVetError)
+    }
+}
+
+/// Validates each of a heterogeneous list of items, stopping at the first
+/// error.
+#[cfg(feature = "alloc")]
+pub fn validate_all(items: &[&dyn ErasedVet]) -> Result<(), BoxedVetError> {
+    for item in items {
+        item.is_valid_erased()?;
+    }
+    Ok(())
+}
+
+/// A named validation rule, as used by `Pipeline` and `RuleSet`
+#[cfg(feature = "alloc")]
+pub type Rule<T> = (
+    alloc::borrow::Cow<'static, str>,
+    alloc::boxed::Box<dyn Fn(&T) -> Result<(), <T as Vet>::Error>>,
+);
+
+/// A dynamically composed sequence of named validation steps
+///
+/// Running a pipeline always validates the value with `is_valid` first, as a
+/// step named `"is_valid"`, followed by each added step in order.
+#[cfg(feature = "alloc")]
+pub struct Pipeline<T: Vet> {
+    steps: alloc::vec::Vec<Rule<T>>,
+    mode: VetMode,
+}
+
+/// The error returned by a failed `Pipeline` step or `RuleSet` rule
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StepError<E> {
+    /// The name of the step which failed.
+    pub step: alloc::borrow::Cow<'static, str>,
+    /// The error returned by the step.
+    pub error: E,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> Pipeline<T> {
+    /// Creates an empty pipeline which stops at the first error.
+    pub fn new() -> Self {
+        Pipeline {
+            steps: alloc::vec::Vec::new(),
+            mode: VetMode::FirstError,
+        }
+    }
+
+    /// Sets whether running the pipeline stops at the first error or runs
+    /// every step.
+    pub fn mode(mut self, mode: VetMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Appends a named step to the pipeline.
+    pub fn step<N, F>(mut self, name: N, check: F) -> Self
+    where
+        N: Into<alloc::borrow::Cow<'static, str>>,
+        F: Fn(&T) -> Result<(), T::Error> + 'static,
+    {
+        self.steps
+            .push((name.into(), alloc::boxed::Box::new(check)));
+        self
+    }
+
+    /// Runs each step in order, resulting in the wrapped value if every step
+    /// passes, or the errors of the failed steps otherwise.
+    pub fn run(&self, value: T) -> Result<Valid<T>, alloc::vec::Vec<StepError<T::Error>>> {
+        let mut errors = alloc::vec::Vec::new();
+        let intrinsic: &dyn Fn(&T) -> Result<(), T::Error> = &T::is_valid;
+        let intrinsic_name = alloc::borrow::Cow::Borrowed("is_valid");
+        let steps = core::iter::once((&intrinsic_name, intrinsic))
+            .chain(self.steps.iter().map(|(name, check)| (name, &**check)));
+        for (step, check) in steps {
+            if let Err(error) = check(&value) {
+                errors.push(StepError {
+                    step: step.clone(),
+                    error,
+                });
+                if self.mode == VetMode::FirstError {
+                    break;
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(Valid(value))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> Default for Pipeline<T> {
+    fn default() -> Self {
+        Pipeline::new()
+    }
+}
+
+/// A reusable set of named validation rules
+///
+/// A rule set is built once, such as from rules loaded from configuration,
+/// and applied to many values. Applying it always validates the value with
+/// `is_valid` first, as a rule named `"is_valid"`, followed by each rule in
+/// order, stopping at the first failure.
+#[cfg(feature = "alloc")]
+pub struct RuleSet<T: Vet> {
+    rules: alloc::vec::Vec<Rule<T>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> RuleSet<T> {
+    /// Creates a rule set from a list of named rules.
+    pub fn new(rules: alloc::vec::Vec<Rule<T>>) -> Self {
+        RuleSet { rules }
+    }
+
+    /// Validates a value against every rule, resulting in the wrapped value
+    /// if every rule passes, or the error of the first failed rule otherwise.
+    pub fn vet(&self, value: T) -> Result<Valid<T>, StepError<T::Error>> {
+        value.is_valid().map_err(|error| StepError {
+            step: alloc::borrow::Cow::Borrowed("is_valid"),
+            error,
+        })?;
+        for (step, rule) in &self.rules {
+            rule(&value).map_err(|error| StepError {
+                step: step.clone(),
+                error,
+            })?;
+        }
+        Ok(Valid(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> FromIterator<Rule<T>> for RuleSet<T> {
+    fn from_iter<I: IntoIterator<Item = Rule<T>>>(iter: I) -> Self {
+        RuleSet::new(iter.into_iter().collect())
+    }
+}
+
+/// Validation methods specific to vectors
+#[cfg(feature = "alloc")]
+pub trait VetVec<T: Vet>: Sized {
+    /// Validates every element, collecting all of the errors on failure.
+    fn vet_all(self) -> Result<Valid<alloc::vec::Vec<T>>, alloc::vec::Vec<T::Error>>;
+
+    /// Validates every element, collecting at most `max_errors` errors on
+    /// failure.
+    ///
+    /// Validation stops as soon as the limit is reached. A limit of zero
+    /// stops at the first failure with no errors collected.
+    fn vet_all_limited(
+        self,
+        max_errors: usize,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, alloc::vec::Vec<T::Error>>;
+
+    /// Validates every element and removes duplicates, keeping the first
+    /// occurrence of each element and preserving order.
+    #[cfg(feature = "std")]
+    fn vet_dedup(self) -> Result<Valid<alloc::vec::Vec<T>>, T::Error>
+    where
+        T: Eq + core::hash::Hash;
+
+    /// Validates that the length is within a range and that every element is
+    /// valid.
+    ///
+    /// The length is checked before any element is validated.
+    fn vet_len_range(
+        self,
+        range: core::ops::RangeInclusive<usize>,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, LenOrElemError<T::Error>>;
+
+    /// Validates every element, resulting in the index and error of the first
+    /// invalid element on failure.
+    fn vet_all_indexed(self) -> Result<Valid<alloc::vec::Vec<T>>, (usize, T::Error)>;
+
+    /// Validates every element in chunks of `PROGRESS_CHUNK`, calling
+    /// `progress(done, total)` after each chunk is validated.
+    ///
+    /// The last chunk may be shorter, so the final call reports
+    /// `done == total`. Validation stops at the first failure without
+    /// reporting the failing chunk.
+    fn vet_progress<F: FnMut(usize, usize)>(
+        self,
+        progress: F,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, T::Error>;
+}
+
+/// The number of elements validated between calls to the progress callback of
+/// `VetVec::vet_progress`
+#[cfg(feature = "alloc")]
+pub const PROGRESS_CHUNK: usize = 1024;
+
+/// The error returned by `VetVec::vet_len_range`
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LenOrElemError<E> {
+    /// The length was out of range.
+    Len(LengthError),
+    /// An element failed validation.
+    Elem(E),
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Vet> VetVec<T> for alloc::vec::Vec<T> {
+    fn vet_all(self) -> Result<Valid<alloc::vec::Vec<T>>, alloc::vec::Vec<T::Error>> {
+        self.vet_all_limited(usize::MAX)
+    }
+
+    fn vet_all_limited(
+        self,
+        max_errors: usize,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, alloc::vec::Vec<T::Error>> {
+        let mut failed = false;
+        let mut errors = alloc::vec::Vec::new();
+        for t in &self {
+            if let Err(e) = t.is_valid() {
+                failed = true;
+                if errors.len() < max_errors {
+                    errors.push(e);
+                }
+                if errors.len() == max_errors {
+                    break;
+                }
+            }
+        }
+        if failed {
+            Err(errors)
+        } else {
+            Ok(Valid(self))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn vet_dedup(self) -> Result<Valid<alloc::vec::Vec<T>>, T::Error>
+    where
+        T: Eq + core::hash::Hash,
+    {
+        let mut seen = std::collections::HashSet::with_capacity(self.len());
+        let mut keep = alloc::vec::Vec::with_capacity(self.len());
+        for t in &self {
+            t.is_valid()?;
+            keep.push(seen.insert(t));
+        }
+        drop(seen);
+        let items = self
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(t, keep)| keep.then_some(t))
+            .collect();
+        Ok(Valid(items))
+    }
+
+    fn vet_len_range(
+        self,
+        range: core::ops::RangeInclusive<usize>,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, LenOrElemError<T::Error>> {
+        LengthError::check(self.len(), *range.start(), *range.end())
+            .map_err(LenOrElemError::Len)?;
+        self.vet().map_err(LenOrElemError::Elem)
+    }
+
+    fn vet_all_indexed(self) -> Result<Valid<alloc::vec::Vec<T>>, (usize, T::Error)> {
+        for (i, t) in self.iter().enumerate() {
+            t.is_valid().map_err(|e| (i, e))?;
+        }
+        Ok(Valid(self))
+    }
+
+    fn vet_progress<F: FnMut(usize, usize)>(
+        self,
+        mut progress: F,
+    ) -> Result<Valid<alloc::vec::Vec<T>>, T::Error> {
+        let total = self.len();
+        for (i, chunk) in self.chunks(PROGRESS_CHUNK).enumerate() {
+            for t in chunk {
+                t.is_valid()?;
+            }
+            progress(i * PROGRESS_CHUNK + chunk.len(), total);
+        }
+        Ok(Valid(self))
+    }
+}
+
+/// Validates every element of an array, converting the error of the first
+/// invalid element into a common error type.
+pub fn vet_array_into<T, E, const N: usize>(arr: [T; N]) -> Result<Valid<[T; N]>, E>
+where
+    T: Vet,
+    T::Error: Into<E>,
+{
+    arr.vet().map_err(Into::into)
+}
+
+/// Validates every element of an array, resulting in the index and error of
+/// the first invalid element on failure.
+pub fn vet_array_indexed<T: Vet, const N: usize>(
+    arr: [T; N],
+) -> Result<Valid<[T; N]>, (usize, T::Error)> {
+    for (i, t) in arr.iter().enumerate() {
+        t.is_valid().map_err(|e| (i, e))?;
+    }
+    Ok(Valid(arr))
+}
+
+/// Reads all input from a reader, parses it, and validates the result.
+#[cfg(feature = "std")]
+pub fn vet_reader<R, T, P, F>(mut reader: R, parse: F) -> Result<Valid<T>, VetReadError<T::Error>>
+where
+    R: std::io::Read,
+    T: Vet,
+    P: Into<alloc::boxed::Box<dyn std::error::Error + Send + Sync>>,
+    F: FnOnce(&[u8]) -> Result<T, P>,
+{
+    let mut buffer = alloc::vec::Vec::new();
+    reader.read_to_end(&mut buffer).map_err(VetReadError::Io)?;
+    let value = parse(&buffer).map_err(|e| VetReadError::Parse(e.into()))?;
+    value.vet().map_err(VetReadError::Invalid)
+}
+
+/// The error returned by `vet_reader`
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum VetReadError<E> {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// Parsing the input failed.
+    Parse(alloc::boxed::Box<dyn std::error::Error + Send + Sync>),
+    /// The parsed value failed validation.
+    Invalid(E),
+}
+
+/// Validates every element of a borrowed slice, resulting in a validated
+/// borrow of the whole slice.
+pub fn vet_all_refs<T: Vet>(items: &[T]) -> Result<Valid<&[T]>, T::Error> {
+    for t in items {
+        t.is_valid()?;
+    }
+    Ok(Valid(items))
+}
+
+/// Validates every value in the map.
+#[cfg(feature = "std")]
+impl<K, V: Vet, S> Vet for std::collections::HashMap<K, V, S> {
+    type Error = V::Error;
+
+    const IS_INFALLIBLE: bool = V::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        for v in self.values() {
+            v.is_valid()?;
+        }
+        Ok(())
+    }
+}
+
+/// Validation methods specific to maps
+#[cfg(any(feature = "std", feature = "indexmap"))]
+pub trait VetMap<K, V> {
+    /// Validates every key in the map.
+    fn vet_keys(&self) -> Result<(), K::Error>
+    where
+        K: Vet;
+
+    /// Validates every key and value in the map.
+    fn vet_entries(&self) -> Result<(), EntryError<K::Error, V::Error>>
+    where
+        K: Vet,
+        V: Vet;
+}
+
+/// The error returned by failed validation of a map entry
+#[cfg(any(feature = "std", feature = "indexmap"))]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EntryError<K, V> {
+    /// The entry's key failed validation.
+    Key(K),
+    /// The entry's value failed validation.
+    Value(V),
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> VetMap<K, V> for std::collections::HashMap<K, V, S> {
+    fn vet_keys(&self) -> Result<(), K::Error>
+    where
+        K: Vet,
+    {
+        for k in self.keys() {
+            k.is_valid()?;
+        }
+        Ok(())
+    }
+
+    fn vet_entries(&self) -> Result<(), EntryError<K::Error, V::Error>>
+    where
+        K: Vet,
+        V: Vet,
+    {
+        for (k, v) in self {
+            k.is_valid().map_err(EntryError::Key)?;
+            v.is_valid().map_err(EntryError::Value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates every value in the map in insertion order.
+#[cfg(feature = "indexmap")]
+impl<K, V: Vet, S> Vet for indexmap::IndexMap<K, V, S> {
+    type Error = V::Error;
+
+    const IS_INFALLIBLE: bool = V::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        for v in self.values() {
+            v.is_valid()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> VetMap<K, V> for indexmap::IndexMap<K, V, S> {
+    fn vet_keys(&self) -> Result<(), K::Error>
+    where
+        K: Vet,
+    {
+        for k in self.keys() {
+            k.is_valid()?;
+        }
+        Ok(())
+    }
+
+    fn vet_entries(&self) -> Result<(), EntryError<K::Error, V::Error>>
+    where
+        K: Vet,
+        V: Vet,
+    {
+        for (k, v) in self {
+            k.is_valid().map_err(EntryError::Key)?;
+            v.is_valid().map_err(EntryError::Value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates every element in the set in insertion order.
+#[cfg(feature = "indexmap")]
+impl<T: Vet, S> Vet for indexmap::IndexSet<T, S> {
+    type Error = T::Error;
+
+    const IS_INFALLIBLE: bool = T::IS_INFALLIBLE;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        for t in self {
+            t.is_valid()?;
+        }
+        Ok(())
+    }
+}
+
+/// Collapses a vector of individually validated elements into a validated
+/// vector without re-validating.
+#[cfg(feature = "alloc")]
+pub fn collect_valid<T>(items: alloc::vec::Vec<Valid<T>>) -> Valid<alloc::vec::Vec<T>> {
+    Valid(items.into_iter().map(Valid::into_inner).collect())
+}
+
+/// Builds a map of validated items keyed by a projection of each item.
+///
+/// If several items have the same key, the last of them is kept.
+#[cfg(feature = "std")]
+pub fn index_by<T, K, F>(
+    items: alloc::vec::Vec<Valid<T>>,
+    key: F,
+) -> std::collections::HashMap<K, Valid<T>>
+where
+    K: Eq + core::hash::Hash,
+    F: Fn(&T) -> K,
+{
+    items.into_iter().map(|t| (key(&t), t)).collect()
+}
+
+/// Sorts a slice of validated values by their wrapped values.
+///
+/// This is equivalent to `items.sort()`, and is stable.
+#[cfg(feature = "alloc")]
+pub fn sort_valid<T: Ord>(items: &mut [Valid<T>]) {
+    items.sort();
+}
+
+/// Validates each item of an iterator, collecting them into a validated
+/// vector and stopping at the first error.
+#[cfg(feature = "alloc")]
+pub fn vet_collect<T, I>(iter: I) -> Result<Valid<alloc::vec::Vec<T>>, T::Error>
+where
+    T: Vet,
+    I: IntoIterator<Item = T>,
+{
+    let iter = iter.into_iter();
+    let mut items = alloc::vec::Vec::with_capacity(iter.size_hint().0);
+    for t in iter {
+        if !T::IS_INFALLIBLE {
+            t.is_valid()?;
+        }
+        items.push(t);
+    }
+    Ok(Valid(items))
+}
+
+/// Validates each item of an iterator, collecting them into a validated
+/// collection of any type and stopping at the first error.
+///
+/// Unlike the `Vet` implementations for collections, this does not require
+/// the target collection to implement `Vet`.
+pub fn vet_collect_into<T, C, I>(iter: I) -> Result<Valid<C>, T::Error>
+where
+    T: Vet,
+    C: FromIterator<T>,
+    I: IntoIterator<Item = T>,
+{
+    iter.into_iter()
+        .map(|t| t.is_valid().map(|()| t))
+        .collect::<Result<C, _>>()
+        .map(Valid)
+}
+
+/// An adapter validating a normalized copy of a value
+///
+/// The normalization function is applied on each validation, and should be
+/// deterministic so that the normalized value is the same one which was
+/// validated.
+#[derive(Clone, Copy, Debug)]
+pub struct Normalized<T, F>(pub T, pub F);
+
+impl<T, F: Fn(&T) -> T> Normalized<T, F> {
+    /// Returns a normalized copy of the value.
+    pub fn normalized(&self) -> T {
+        (self.1)(&self.0)
+    }
+}
+
+impl<T: Vet, F: Fn(&T) -> T> Vet for Normalized<T, F> {
+    type Error = T::Error;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        self.normalized().is_valid()
+    }
+}
+
+impl<T: Vet, F: Fn(&T) -> T> Valid<Normalized<T, F>> {
+    /// Consumes the wrapper, resulting in the validated normalized value.
+    pub fn into_normalized(self) -> Valid<T> {
+        Valid(self.0.normalized())
+    }
+}
+
+/// An adapter validating that a range's start does not exceed its end
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WellFormedRange<T>(pub core::ops::Range<T>);
+
+/// The error returned when a range's start exceeds its end
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StartAfterEnd;
+
+impl<T: PartialOrd> Vet for WellFormedRange<T> {
+    type Error = StartAfterEnd;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.start <= self.0.end {
+            Ok(())
+        } else {
+            Err(StartAfterEnd)
+        }
+    }
+}
+
+/// An adapter validating that an inclusive range's start does not exceed its
+/// end
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WellFormedInclusive<T>(pub core::ops::RangeInclusive<T>);
+
+impl<T: PartialOrd> Vet for WellFormedInclusive<T> {
+    type Error = StartAfterEnd;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.start() <= self.0.end() {
+            Ok(())
+        } else {
+            Err(StartAfterEnd)
+        }
+    }
+}
+
+/// An adapter validating that an optional value is present and valid
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Required<T>(pub Option<T>);
+
+/// The error returned by failed validation of a `Required` value
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RequiredError<E> {
+    /// The value was not present.
+    Missing,
+    /// The value was present but failed validation.
+    Invalid(E),
+}
+
+impl<T: Vet> Vet for Required<T> {
+    type Error = RequiredError<T::Error>;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match &self.0 {
+            Some(t) => t.is_valid().map_err(RequiredError::Invalid),
+            None => Err(RequiredError::Missing),
+        }
+    }
+}
+
+/// An adapter validating that a result is `Ok` and its value is valid
+///
+/// On failure due to an `Err`, the error is cloned into the validation error.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RequireOk<T, E>(pub Result<T, E>);
+
+/// The error returned by failed validation of a `RequireOk` value
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RequireOkError<E, V> {
+    /// The result was an `Err`.
+    Err(E),
+    /// The result was `Ok` but its value failed validation.
+    Invalid(V),
+}
+
+impl<T: Vet, E: Clone> Vet for RequireOk<T, E> {
+    type Error = RequireOkError<E, T::Error>;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match &self.0 {
+            Ok(t) => t.is_valid().map_err(RequireOkError::Invalid),
+            Err(e) => Err(RequireOkError::Err(e.clone())),
+        }
+    }
+}
+
+/// An adapter validating that a path exists on disk
+///
+/// Validation queries the filesystem, so the result reflects the state of the
+/// disk at the time of the check only.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExistingPath(pub std::path::PathBuf);
+
+/// The error returned when a path does not exist on disk
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PathNotFound;
+
+#[cfg(feature = "std")]
+impl Vet for ExistingPath {
+    type Error = PathNotFound;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.exists() {
+            Ok(())
+        } else {
+            Err(PathNotFound)
+        }
+    }
+}
+
+/// An adapter validating that a path is absolute
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AbsolutePath(pub std::path::PathBuf);
+
+/// The error returned when a path is not absolute
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RelativePath;
+
+#[cfg(feature = "std")]
+impl Vet for AbsolutePath {
+    type Error = RelativePath;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.is_absolute() {
+            Ok(())
+        } else {
+            Err(RelativePath)
+        }
+    }
+}
+
+/// An adapter validating a snapshot of the value behind a `Mutex` or `RwLock`
+///
+/// The lock is held only for the duration of validation, so the value may
+/// change afterwards. A `Valid<SnapshotVet<L>>` only guarantees that the
+/// value was valid at the time it was validated.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SnapshotVet<'a, L>(pub &'a L);
+
+/// The error returned by failed validation of a `SnapshotVet`
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SnapshotError<E> {
+    /// The lock was poisoned by a panic while held.
+    Poisoned,
+    /// The value failed validation.
+    Invalid(E),
+}
+
+#[cfg(feature = "std")]
+impl<T: Vet> Vet for SnapshotVet<'_, std::sync::Mutex<T>> {
+    type Error = SnapshotError<T::Error>;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        let guard = self.0.lock().map_err(|_| SnapshotError::Poisoned)?;
+        guard.is_valid().map_err(SnapshotError::Invalid)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Vet> Vet for SnapshotVet<'_, std::sync::RwLock<T>> {
+    type Error = SnapshotError<T::Error>;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        let guard = self.0.read().map_err(|_| SnapshotError::Poisoned)?;
+        guard.is_valid().map_err(SnapshotError::Invalid)
+    }
+}
+
+/// An adapter validating that an IP address is not a loopback address
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonLoopbackIp(pub core::net::IpAddr);
+
+/// The error returned when an IP address is a loopback address
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LoopbackIp;
+
+impl Vet for NonLoopbackIp {
+    type Error = LoopbackIp;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.to_canonical().is_loopback() {
+            Err(LoopbackIp)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// An adapter validating that an IP address belongs to a private network
+///
+/// Private IPv4 addresses are those defined by RFC 1918, and private IPv6
+/// addresses are the unique local addresses defined by RFC 4193.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PrivateIp(pub core::net::IpAddr);
+
+/// The error returned when an IP address does not belong to a private network
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonPrivateIp;
+
+impl Vet for PrivateIp {
+    type Error = NonPrivateIp;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        let private = match self.0.to_canonical() {
+            core::net::IpAddr::V4(ip) => ip.is_private(),
+            core::net::IpAddr::V6(ip) => ip.is_unique_local(),
+        };
+        if private {
+            Ok(())
+        } else {
+            Err(NonPrivateIp)
+        }
+    }
+}
+
+/// An adapter validating that an IP address is publicly routable
+///
+/// Addresses in the special-purpose ranges described by the variants of
+/// `NonPublicIp` are rejected. IPv4 addresses embedded in IPv4-mapped, NAT64,
+/// and 6to4 IPv6 addresses are checked as IPv4 addresses, so that a
+/// non-public IPv4 address cannot be smuggled inside an IPv6 address.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PublicIp(pub core::net::IpAddr);
+
+/// The error returned when an IP address is not publicly routable
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NonPublicIp {
+    /// The address is the unspecified address.
+    Unspecified,
+    /// The address is a loopback address.
+    Loopback,
+    /// The address belongs to a private network.
+    Private,
+    /// The address belongs to the shared address space used for carrier-grade
+    /// NAT (`100.64.0.0/10`).
+    Shared,
+    /// The address is a link-local address.
+    LinkLocal,
+    /// The address is reserved for documentation.
+    Documentation,
+    /// The address is reserved for another special purpose, such as
+    /// benchmarking, protocol assignments, segment routing, or future use.
+    Reserved,
+    /// The address is a multicast or broadcast address.
+    Multicast,
+}
+
+impl Vet for PublicIp {
+    type Error = NonPublicIp;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self.0.to_canonical() {
+            core::net::IpAddr::V4(ip) => public_ipv4(ip),
+            core::net::IpAddr::V6(ip) => public_ipv6(ip),
+        }
+    }
+}
+
+fn public_ipv4(ip: core::net::Ipv4Addr) -> Result<(), NonPublicIp> {
+    let [a, b, c, _] = ip.octets();
+    if ip.is_unspecified() {
+        Err(NonPublicIp::Unspecified)
+    } else if ip.is_loopback() {
+        Err(NonPublicIp::Loopback)
+    } else if ip.is_private() {
+        Err(NonPublicIp::Private)
+    } else if a == 100 && b & 0xc0 == 64 {
+        Err(NonPublicIp::Shared)
+    } else if ip.is_link_local() {
+        Err(NonPublicIp::LinkLocal)
+    } else if ip.is_documentation() {
+        Err(NonPublicIp::Documentation)
+    } else if ip.is_multicast() || ip.is_broadcast() {
+        Err(NonPublicIp::Multicast)
+    } else if a == 0
+        || (a, b, c) == (192, 0, 0)
+        || (a, b, c) == (192, 88, 99)
+        || (a == 198 && b & 0xfe == 18)
+        || a >= 240
+    {
+        Err(NonPublicIp::Reserved)
+    } else {
+        Ok(())
+    }
+}
+
+fn public_ipv6(ip: core::net::Ipv6Addr) -> Result<(), NonPublicIp> {
+    let s = ip.segments();
+    let embedded = |hi: u16, lo: u16| {
+        let [a, b] = hi.to_be_bytes();
+        let [c, d] = lo.to_be_bytes();
+        public_ipv4(core::net::Ipv4Addr::new(a, b, c, d))
+    };
+    if ip.is_unspecified() {
+        Err(NonPublicIp::Unspecified)
+    } else if ip.is_loopback() {
+        Err(NonPublicIp::Loopback)
+    } else if s[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        embedded(s[6], s[7])
+    } else if s[0] == 0x2002 {
+        embedded(s[1], s[2])
+    } else if ip.is_unique_local() || s[..3] == [0x64, 0xff9b, 1] {
+        Err(NonPublicIp::Private)
+    } else if ip.is_unicast_link_local() {
+        Err(NonPublicIp::LinkLocal)
+    } else if s[..2] == [0x2001, 0xdb8] || (s[0] == 0x3fff && s[1] & 0xf000 == 0) {
+        Err(NonPublicIp::Documentation)
+    } else if ip.is_multicast() {
+        Err(NonPublicIp::Multicast)
+    } else if s[..6] == [0; 6]
+        || s[..4] == [0x100, 0, 0, 0]
+        || s[..2] == [0x2001, 0]
+        || s[..3] == [0x2001, 2, 0]
+        || s[0] & 0xffc0 == 0xfec0
+        || s[0] == 0x5f00
+    {
+        Err(NonPublicIp::Reserved)
+    } else {
+        Ok(())
+    }
+}
+
+/// An adapter validating that a date-time falls within an inclusive range
+#[cfg(feature = "time")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DateTimeInRange {
+    /// The date-time to validate
+    pub value: time::OffsetDateTime,
+    /// The range of acceptable date-times
+    pub range: core::ops::RangeInclusive<time::OffsetDateTime>,
+}
+
+/// The error returned when a date-time falls outside of its range
+#[cfg(feature = "time")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DateTimeOutOfRange {
+    /// The date-time is before the start of the range.
+    TooEarly,
+    /// The date-time is after the end of the range.
+    TooLate,
+}
+
+#[cfg(feature = "time")]
+impl Vet for DateTimeInRange {
+    type Error = DateTimeOutOfRange;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.value < *self.range.start() {
+            Err(DateTimeOutOfRange::TooEarly)
+        } else if self.value > *self.range.end() {
+            Err(DateTimeOutOfRange::TooLate)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// An adapter validating that a date is not later than a reference date
+#[cfg(feature = "time")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NotFutureDate {
+    /// The date to validate
+    pub date: time::Date,
+    /// The date considered to be today
+    pub today: time::Date,
+}
+
+#[cfg(all(feature = "time", feature = "std"))]
+impl NotFutureDate {
+    /// Creates an adapter checking the date against the current UTC date.
+    pub fn today(date: time::Date) -> Self {
+        NotFutureDate {
+            date,
+            today: time::OffsetDateTime::now_utc().date(),
+        }
+    }
+}
+
+/// The error returned when a date is in the future
+#[cfg(feature = "time")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FutureDate;
+
+#[cfg(feature = "time")]
+impl Vet for NotFutureDate {
+    type Error = FutureDate;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.date <= self.today {
+            Ok(())
+        } else {
+            Err(FutureDate)
+        }
+    }
+}
+
+/// An adapter validating that a floating point number is neither NaN nor
+/// infinite
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Finite<T>(pub T);
+
+/// The error returned when a floating point number is NaN or infinite
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotFinite;
+
+impl Vet for Finite<f32> {
+    type Error = NotFinite;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.is_finite() {
+            Ok(())
+        } else {
+            Err(NotFinite)
+        }
+    }
+}
+
+impl Vet for Finite<f64> {
+    type Error = NotFinite;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.is_finite() {
+            Ok(())
+        } else {
+            Err(NotFinite)
+        }
+    }
+}
+
+/// An adapter validating that a number is even if `EVEN` is `true`, or odd
+/// otherwise
+///
+/// The rule is selected at compile time, so `ParityChecked<true>` and
+/// `ParityChecked<false>` are distinct types with distinct guarantees.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParityChecked<const EVEN: bool>(pub usize);
+
+/// The error returned when a number does not have the expected parity
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParityError;
+
+impl<const EVEN: bool> Vet for ParityChecked<EVEN> {
+    type Error = ParityError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if (self.0 % 2 == 0) == EVEN {
+            Ok(())
+        } else {
+            Err(ParityError)
+        }
+    }
+}
+
+/// An adapter validating that a string's length in bytes is within
+/// `MIN..=MAX`
+///
+/// The byte length of a string is only equal to its number of characters for
+/// ASCII text. Use `CharLen` to bound the number of characters instead, such as
+/// for user-facing text limits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByteLen<S, const MIN: usize, const MAX: usize>(pub S);
+
+/// An adapter validating that a string's length in characters is within
+/// `MIN..=MAX`
+///
+/// Characters are counted as Unicode scalar values. Use `ByteLen` to bound the
+/// encoded size of a string instead, such as for storage limits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CharLen<S, const MIN: usize, const MAX: usize>(pub S);
+
+/// The error returned when a length is out of bounds
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LengthError {
+    /// The length is below the minimum.
+    TooShort,
+    /// The length is above the maximum.
+    TooLong,
+}
+
+impl LengthError {
+    fn check(len: usize, min: usize, max: usize) -> Result<(), Self> {
+        if len < min {
+            Err(LengthError::TooShort)
+        } else if len > max {
+            Err(LengthError::TooLong)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<S: AsRef<str>, const MIN: usize, const MAX: usize> Vet for ByteLen<S, MIN, MAX> {
+    type Error = LengthError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        LengthError::check(self.0.as_ref().len(), MIN, MAX)
+    }
+}
+
+impl<S: AsRef<str>, const MIN: usize, const MAX: usize> Vet for CharLen<S, MIN, MAX> {
+    type Error = LengthError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        LengthError::check(self.0.as_ref().chars().count(), MIN, MAX)
+    }
+}
+
+/// An adapter validating that a C string contains valid UTF-8
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Utf8CStr<S>(pub S);
+
+/// The error returned when a C string does not contain valid UTF-8
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotUtf8;
+
+impl<S: AsRef<core::ffi::CStr>> Vet for Utf8CStr<S> {
+    type Error = NotUtf8;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self.0.as_ref().to_str() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(NotUtf8),
+        }
+    }
+}
+
+/// An adapter validating that a C string's length in bytes, excluding the nul
+/// terminator, is within `MIN..=MAX`
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CStrLen<S, const MIN: usize, const MAX: usize>(pub S);
+
+impl<S: AsRef<core::ffi::CStr>, const MIN: usize, const MAX: usize> Vet for CStrLen<S, MIN, MAX> {
+    type Error = LengthError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        LengthError::check(self.0.as_ref().count_bytes(), MIN, MAX)
+    }
+}
+
+/// An adapter validating that a `bytes` buffer is not empty
+#[cfg(feature = "bytes")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonEmptyBytes<B>(pub B);
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::Buf> Vet for NonEmptyBytes<B> {
+    type Error = LengthError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.has_remaining() {
+            Ok(())
+        } else {
+            Err(LengthError::TooShort)
+        }
+    }
+}
+
+/// An adapter validating that a `bytes` buffer holds at most `N` bytes
+#[cfg(feature = "bytes")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MaxBytes<B, const N: usize>(pub B);
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::Buf, const N: usize> Vet for MaxBytes<B, N> {
+    type Error = LengthError;
+
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        if self.0.remaining() <= N {
+            Ok(())
+        } else {
+            Err(LengthError::TooLong)
+        }
+    }
+}
+
+/// A validation error described by a static message
+///
+/// This allows errors to carry a human-readable message without allocating,
+/// including in `no_std` environments. The built-in adapters' errors convert
+/// into it with a fixed message.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StaticStrError(pub &'static str);
+
+impl StaticStrError {
+    /// Returns the error message.
+    pub fn message(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl core::fmt::Display for StaticStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaticStrError {}
+
+impl From<&'static str> for StaticStrError {
+    fn from(message: &'static str) -> Self {
+        StaticStrError(message)
+    }
+}
+
+macro_rules! impl_static_str_error {
+    ($($(#[$attr:meta])* $ty:ident: $($pat:pat => $msg:literal),+;)+) => {
+        $(
+            $(#[$attr])*
+            impl From<$ty> for StaticStrError {
+                fn from(error: $ty) -> Self {
+                    StaticStrError(match error {
+                        $($pat => $msg,)+
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_static_str_error! {
+    StartAfterEnd: StartAfterEnd => "range start is after its end";
+    #[cfg(feature = "std")]
+    PathNotFound: PathNotFound => "path does not exist";
+    #[cfg(feature = "std")]
+    RelativePath: RelativePath => "path is not absolute";
+    LoopbackIp: LoopbackIp => "address is a loopback address";
+    NonPrivateIp: NonPrivateIp => "address is not a private address";
+    NonPublicIp:
+        NonPublicIp::Unspecified => "address is the unspecified address",
+        NonPublicIp::Loopback => "address is a loopback address",
+        NonPublicIp::Private => "address is a private address",
+        NonPublicIp::Shared => "address is a shared address",
+        NonPublicIp::LinkLocal => "address is a link-local address",
+        NonPublicIp::Documentation => "address is reserved for documentation",
+        NonPublicIp::Reserved => "address is reserved",
+        NonPublicIp::Multicast => "address is a multicast address";
+    #[cfg(feature = "time")]
+    DateTimeOutOfRange:
+        DateTimeOutOfRange::TooEarly => "date-time is too early",
+        DateTimeOutOfRange::TooLate => "date-time is too late";
+    #[cfg(feature = "time")]
+    FutureDate: FutureDate => "date is in the future";
+    NotFinite: NotFinite => "number is not finite";
+    ParityError: ParityError => "number does not have the expected parity";
+    LengthError:
+        LengthError::TooShort => "length is too short",
+        LengthError::TooLong => "length is too long";
+    NotUtf8: NotUtf8 => "string is not valid UTF-8";
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 struct EvenUsize(usize);
 
 #[derive(Debug)]
@@ -17,6 +17,13 @@ impl Vet for EvenUsize {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EvenUsize {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EvenUsize(usize::arbitrary(u)?))
+    }
+}
+
 #[test]
 fn vet_type() {
     let foo = EvenUsize(2);
@@ -26,6 +33,43 @@ fn vet_type() {
     assert!(foo.is_valid().is_err());
 }
 
+#[test]
+fn vet_type_outcome() {
+    match EvenUsize(6).vet_outcome() {
+        Outcome::Valid(v) => assert_eq!(v.into_inner(), EvenUsize(6)),
+        Outcome::Invalid(..) => panic!("expected valid outcome"),
+    }
+
+    match EvenUsize(7).vet_outcome() {
+        Outcome::Valid(_) => panic!("expected invalid outcome"),
+        Outcome::Invalid(value, OddUsize) => assert_eq!(value, EvenUsize(7)),
+    }
+}
+
+#[test]
+fn compare_valid_by_key() {
+    use core::cmp::Ordering;
+
+    #[derive(Debug)]
+    struct Small(u8);
+
+    impl Vet for Small {
+        type Error = ();
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    let even = EvenUsize(4).vet().unwrap();
+    let small = Small(7).vet().unwrap();
+
+    let ordering = even.compare_by(&small, |e| e.0, |s| usize::from(s.0));
+    assert_eq!(ordering, Ordering::Less);
+
+    let ordering = small.compare_by(&even, |s| usize::from(s.0), |e| e.0);
+    assert_eq!(ordering, Ordering::Greater);
+}
+
 #[test]
 fn vet_array() {
     let foo: [EvenUsize; 0] = [];
@@ -76,3 +120,2186 @@ fn vet_vec() {
     let foo = vec![EvenUsize(8), EvenUsize(7)];
     assert!(foo.is_valid().is_err());
 }
+
+#[test]
+fn vet_well_formed_range() {
+    let foo = WellFormedRange(2..8);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = WellFormedRange(5..5);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = WellFormedRange(core::ops::Range { start: 8, end: 2 });
+    assert_eq!(foo.is_valid(), Err(StartAfterEnd));
+}
+
+#[test]
+fn vet_required() {
+    let foo = Required(None::<EvenUsize>);
+    assert!(matches!(foo.is_valid(), Err(RequiredError::Missing)));
+
+    let foo = Required(Some(EvenUsize(10)));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = Required(Some(EvenUsize(11)));
+    assert!(matches!(
+        foo.is_valid(),
+        Err(RequiredError::Invalid(OddUsize))
+    ));
+}
+
+#[test]
+fn map_ref_valid() {
+    #[derive(Debug)]
+    struct Account {
+        name: &'static str,
+        id: EvenUsize,
+    }
+
+    impl Vet for Account {
+        type Error = OddUsize;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            self.id.is_valid()
+        }
+    }
+
+    let account = Account {
+        name: "benjamin",
+        id: EvenUsize(16),
+    }
+    .vet()
+    .unwrap();
+
+    let name = account.map_ref(|a| a.name);
+    assert_eq!(*name, "benjamin");
+
+    let id = account.map_ref(|a| &a.id);
+    assert_eq!(**id, EvenUsize(16));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn collect_valid_vec() {
+    use alloc::vec::Vec;
+
+    let items: Vec<Valid<EvenUsize>> = [2, 4, 6]
+        .into_iter()
+        .map(|i| EvenUsize(i).vet().unwrap())
+        .collect();
+
+    let foo = collect_valid(items);
+    assert_eq!(*foo, [EvenUsize(2), EvenUsize(4), EvenUsize(6)]);
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary_valid() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let data = [[1; 8], [2; 8]].concat();
+    let foo = Valid::<EvenUsize>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(foo.into_inner(), EvenUsize(usize::from_ne_bytes([2; 8])));
+
+    let data = [1; 256];
+    let foo = Valid::<EvenUsize>::arbitrary(&mut Unstructured::new(&data));
+    assert!(foo.is_err());
+}
+
+#[test]
+fn recheck_valid() {
+    let foo = EvenUsize(22).vet().unwrap();
+    assert!(foo.recheck().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_existing_path() {
+    let dir = std::env::temp_dir();
+
+    let foo = ExistingPath(dir.clone());
+    assert!(foo.is_valid().is_ok());
+
+    let foo = ExistingPath(dir.join("vet-nonexistent-path-2f9c"));
+    assert_eq!(foo.is_valid(), Err(PathNotFound));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_absolute_path() {
+    let foo = AbsolutePath(std::env::temp_dir());
+    assert!(foo.is_valid().is_ok());
+
+    let foo = AbsolutePath(std::path::PathBuf::from("relative/path"));
+    assert_eq!(foo.is_valid(), Err(RelativePath));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_collect_iter() {
+    let foo = vet_collect((0..4).map(|i| EvenUsize(i * 2))).unwrap();
+    assert_eq!(
+        *foo,
+        [EvenUsize(0), EvenUsize(2), EvenUsize(4), EvenUsize(6)]
+    );
+
+    let mut visited = 0;
+    let foo = vet_collect([2, 3, 5, 8].into_iter().map(|i| {
+        visited += 1;
+        EvenUsize(i)
+    }));
+    assert!(foo.is_err());
+    assert_eq!(visited, 2);
+}
+
+#[test]
+fn vet_ip_classes() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let loopback = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let private = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20));
+    let public = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1));
+    let unique_local = IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1));
+    let public_v6 = IpAddr::V6(Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111));
+
+    assert_eq!(NonLoopbackIp(loopback).is_valid(), Err(LoopbackIp));
+    assert_eq!(
+        NonLoopbackIp(IpAddr::V6(Ipv6Addr::LOCALHOST)).is_valid(),
+        Err(LoopbackIp)
+    );
+    assert!(NonLoopbackIp(private).is_valid().is_ok());
+
+    assert!(PrivateIp(private).is_valid().is_ok());
+    assert!(PrivateIp(unique_local).is_valid().is_ok());
+    assert_eq!(PrivateIp(public).is_valid(), Err(NonPrivateIp));
+    assert_eq!(PrivateIp(loopback).is_valid(), Err(NonPrivateIp));
+
+    assert!(PublicIp(public).is_valid().is_ok());
+    assert!(PublicIp(public_v6).is_valid().is_ok());
+    assert_eq!(PublicIp(loopback).is_valid(), Err(NonPublicIp::Loopback));
+    assert_eq!(PublicIp(private).is_valid(), Err(NonPublicIp::Private));
+    assert_eq!(PublicIp(unique_local).is_valid(), Err(NonPublicIp::Private));
+}
+
+#[test]
+fn vet_public_ip_special_purpose() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let v4 = |a, b, c, d| PublicIp(IpAddr::V4(Ipv4Addr::new(a, b, c, d))).is_valid();
+    let v6 = |s: [u16; 8]| PublicIp(IpAddr::V6(Ipv6Addr::from(s))).is_valid();
+
+    assert!(v4(8, 8, 8, 8).is_ok());
+    assert_eq!(v4(100, 64, 0, 1), Err(NonPublicIp::Shared));
+    assert_eq!(v4(100, 127, 255, 254), Err(NonPublicIp::Shared));
+    assert!(v4(100, 128, 0, 1).is_ok());
+    assert_eq!(v4(0, 1, 2, 3), Err(NonPublicIp::Reserved));
+    assert_eq!(v4(198, 18, 0, 1), Err(NonPublicIp::Reserved));
+    assert_eq!(v4(198, 19, 255, 1), Err(NonPublicIp::Reserved));
+    assert_eq!(v4(192, 0, 0, 8), Err(NonPublicIp::Reserved));
+    assert_eq!(v4(240, 0, 0, 1), Err(NonPublicIp::Reserved));
+    assert_eq!(v4(255, 255, 255, 255), Err(NonPublicIp::Multicast));
+
+    assert_eq!(
+        v6([0x64, 0xff9b, 0, 0, 0, 0, 0x7f00, 1]),
+        Err(NonPublicIp::Loopback)
+    );
+    assert!(v6([0x64, 0xff9b, 0, 0, 0, 0, 0x0808, 0x0808]).is_ok());
+    assert_eq!(
+        v6([0x2002, 0xc0a8, 0x0101, 0, 0, 0, 0, 1]),
+        Err(NonPublicIp::Private)
+    );
+    assert_eq!(
+        v6([0, 0, 0, 0, 0, 0xffff, 0x6440, 1]),
+        Err(NonPublicIp::Shared)
+    );
+    assert_eq!(
+        v6([0, 0, 0, 0, 0, 0, 0x0808, 0x0808]),
+        Err(NonPublicIp::Reserved)
+    );
+    assert_eq!(v6([0x100, 0, 0, 0, 0, 0, 0, 1]), Err(NonPublicIp::Reserved));
+    assert_eq!(
+        v6([0x2001, 2, 0, 0, 0, 0, 0, 1]),
+        Err(NonPublicIp::Reserved)
+    );
+    assert_eq!(
+        v6([0x3fff, 0, 0, 0, 0, 0, 0, 1]),
+        Err(NonPublicIp::Documentation)
+    );
+    assert_eq!(
+        v6([0x3fff, 0x0fff, 0, 0, 0, 0, 0, 1]),
+        Err(NonPublicIp::Documentation)
+    );
+    assert!(v6([0x3ff0, 0, 0, 0, 0, 0, 0, 1]).is_ok());
+    assert!(v6([0x3ffe, 1, 0, 0, 0, 0, 0, 1]).is_ok());
+    assert!(v6([0x3fff, 0x1000, 0, 0, 0, 0, 0, 1]).is_ok());
+    assert_eq!(
+        v6([0x5f00, 1, 0, 0, 0, 0, 0, 1]),
+        Err(NonPublicIp::Reserved)
+    );
+}
+
+#[test]
+fn scope_valid() {
+    let foo = EvenUsize(10).vet().unwrap();
+    let (foo, previous) = foo.scope(|e| core::mem::replace(&mut e.0, 12)).unwrap();
+    assert_eq!(*foo, EvenUsize(12));
+    assert_eq!(previous, 10);
+
+    let foo = EvenUsize(10).vet().unwrap();
+    match foo.scope(|e| e.0 += 1) {
+        Ok(_) => panic!("expected re-validation to fail"),
+        Err((value, OddUsize)) => assert_eq!(value, EvenUsize(11)),
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_dedup_vec() {
+    use alloc::vec;
+
+    let foo = vec![
+        EvenUsize(4),
+        EvenUsize(2),
+        EvenUsize(4),
+        EvenUsize(8),
+        EvenUsize(2),
+    ];
+    let foo = foo.vet_dedup().unwrap();
+    assert_eq!(*foo, [EvenUsize(4), EvenUsize(2), EvenUsize(8)]);
+
+    let foo = vec![EvenUsize(4), EvenUsize(4), EvenUsize(9)];
+    assert!(foo.vet_dedup().is_err());
+}
+
+#[test]
+fn vet_infallible_hint() {
+    struct AnyUsize;
+
+    impl Vet for AnyUsize {
+        type Error = core::convert::Infallible;
+        const IS_INFALLIBLE: bool = true;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    const {
+        assert!(AnyUsize::IS_INFALLIBLE);
+        assert!(<[AnyUsize; 2]>::IS_INFALLIBLE);
+        assert!(<Option<AnyUsize>>::IS_INFALLIBLE);
+        assert!(!EvenUsize::IS_INFALLIBLE);
+        assert!(!<Option<EvenUsize>>::IS_INFALLIBLE);
+    }
+    assert!(AnyUsize.vet().is_ok());
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn vet_not_future_date() {
+    use time::{Date, Month};
+
+    let today = Date::from_calendar_date(2024, Month::June, 15).unwrap();
+
+    let past = Date::from_calendar_date(2023, Month::December, 31).unwrap();
+    assert!(NotFutureDate { date: past, today }.is_valid().is_ok());
+
+    assert!(NotFutureDate { date: today, today }.is_valid().is_ok());
+
+    let future = Date::from_calendar_date(2024, Month::June, 16).unwrap();
+    assert_eq!(
+        NotFutureDate {
+            date: future,
+            today
+        }
+        .is_valid(),
+        Err(FutureDate)
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn vet_date_time_in_range() {
+    use time::{Duration, OffsetDateTime};
+
+    let now = OffsetDateTime::UNIX_EPOCH + Duration::days(20_000);
+    let range = (now - Duration::days(30))..=now;
+
+    let foo = DateTimeInRange {
+        value: now - Duration::days(1),
+        range: range.clone(),
+    };
+    assert!(foo.is_valid().is_ok());
+
+    let foo = DateTimeInRange {
+        value: now,
+        range: range.clone(),
+    };
+    assert!(foo.is_valid().is_ok());
+
+    let foo = DateTimeInRange {
+        value: now + Duration::seconds(1),
+        range: range.clone(),
+    };
+    assert_eq!(foo.is_valid(), Err(DateTimeOutOfRange::TooLate));
+
+    let foo = DateTimeInRange {
+        value: now - Duration::days(31),
+        range,
+    };
+    assert_eq!(foo.is_valid(), Err(DateTimeOutOfRange::TooEarly));
+}
+
+#[test]
+fn vet_macro() {
+    fn sum(a: usize, b: usize) -> Result<usize, OddUsize> {
+        let a = vet!(EvenUsize(a));
+        let b = vet!(EvenUsize(b));
+        Ok(a.into_inner().0 + b.into_inner().0)
+    }
+
+    assert_eq!(sum(2, 4).unwrap(), 6);
+    assert!(sum(2, 3).is_err());
+    assert!(sum(1, 4).is_err());
+}
+
+#[test]
+fn vet_graded() {
+    assert!(EvenUsize(4).is_valid_graded().is_ok());
+    assert!(matches!(
+        EvenUsize(5).is_valid_graded(),
+        Err((Severity::Error, OddUsize))
+    ));
+
+    struct Password(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    enum InvalidPassword {
+        Empty,
+        Short,
+    }
+
+    impl Vet for Password {
+        type Error = InvalidPassword;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            match self.is_valid_graded() {
+                Err((Severity::Error, e)) => Err(e),
+                _ => Ok(()),
+            }
+        }
+        fn is_valid_graded(&self) -> Result<(), (Severity, Self::Error)> {
+            match self.0.len() {
+                0 => Err((Severity::Error, InvalidPassword::Empty)),
+                1..=7 => Err((Severity::Warning, InvalidPassword::Short)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    assert_eq!(
+        Password("").is_valid_graded(),
+        Err((Severity::Error, InvalidPassword::Empty))
+    );
+    assert_eq!(
+        Password("hunter2").is_valid_graded(),
+        Err((Severity::Warning, InvalidPassword::Short))
+    );
+    assert!(Password("correct horse").is_valid_graded().is_ok());
+
+    assert!(Password("hunter2").is_valid().is_ok());
+    assert_eq!(Password("").is_valid(), Err(InvalidPassword::Empty));
+}
+
+#[test]
+fn vet_reference() {
+    let foo = &EvenUsize(2);
+    assert!(Vet::is_valid(&foo).is_ok());
+    assert_eq!(*foo.vet().unwrap().into_inner(), EvenUsize(2));
+
+    let foo = &EvenUsize(3);
+    assert!(foo.vet().is_err());
+}
+
+#[test]
+fn vet_array_of_references() {
+    let a = EvenUsize(2);
+    let b = EvenUsize(6);
+    let c = EvenUsize(9);
+
+    let foo = [&a, &b];
+    assert!(foo.is_valid().is_ok());
+    assert!(foo.vet().is_ok());
+
+    let foo = [&a, &c];
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+fn valid_field_macro() {
+    #[derive(Debug)]
+    struct Pair {
+        left: EvenUsize,
+        right: EvenUsize,
+    }
+
+    impl Vet for Pair {
+        type Error = OddUsize;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            self.left.is_valid()?;
+            self.right.is_valid()
+        }
+    }
+
+    let pair = Pair {
+        left: EvenUsize(2),
+        right: EvenUsize(4),
+    }
+    .vet()
+    .unwrap();
+
+    let left: Valid<&EvenUsize> = valid_field!(pair.left);
+    let right: Valid<&EvenUsize> = valid_field!(pair.right);
+    assert_eq!(**left, EvenUsize(2));
+    assert_eq!(**right, EvenUsize(4));
+
+    let even = EvenUsize(6).vet().unwrap();
+    let inner: Valid<&usize> = valid_field!(even.0);
+    assert_eq!(**inner, 6);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_all_vec() {
+    use alloc::vec;
+
+    let foo = vec![EvenUsize(2), EvenUsize(4)];
+    assert!(foo.vet_all().is_ok());
+
+    let foo = vec![EvenUsize(1), EvenUsize(4), EvenUsize(3), EvenUsize(5)];
+    assert_eq!(foo.vet_all().unwrap_err().len(), 3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_all_limited_vec() {
+    use alloc::vec;
+
+    let foo = vec![EvenUsize(1), EvenUsize(3), EvenUsize(5), EvenUsize(7)];
+    assert_eq!(foo.vet_all_limited(2).unwrap_err().len(), 2);
+
+    let foo = vec![EvenUsize(1), EvenUsize(2), EvenUsize(4)];
+    assert_eq!(foo.vet_all_limited(5).unwrap_err().len(), 1);
+
+    let foo = vec![EvenUsize(1)];
+    assert!(foo.vet_all_limited(0).unwrap_err().is_empty());
+
+    let foo = vec![EvenUsize(2), EvenUsize(4)];
+    assert!(foo.vet_all_limited(1).is_ok());
+}
+
+#[test]
+fn vet_once_cell() {
+    use core::cell::OnceCell;
+
+    let foo = OnceCell::<EvenUsize>::new();
+    assert!(foo.is_valid().is_ok());
+
+    let foo = OnceCell::from(EvenUsize(2));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = OnceCell::from(EvenUsize(3));
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_once_lock() {
+    use std::sync::OnceLock;
+
+    let foo = OnceLock::<EvenUsize>::new();
+    assert!(foo.is_valid().is_ok());
+
+    let foo = OnceLock::from(EvenUsize(2));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = OnceLock::from(EvenUsize(3));
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+fn dangerous_get_mut_valid() {
+    let mut foo = EvenUsize(2).vet().unwrap();
+
+    // SAFETY: The value remains even.
+    unsafe { foo.dangerous_get_mut().0 = 8 };
+    assert!(foo.recheck().is_ok());
+    assert_eq!(*foo, EvenUsize(8));
+
+    // SAFETY: The invalid value is only observed through `recheck`.
+    unsafe { foo.dangerous_get_mut().0 = 9 };
+    assert!(foo.recheck().is_err());
+}
+
+#[test]
+fn vet_all_refs_slice() {
+    let buffer = [EvenUsize(2), EvenUsize(4), EvenUsize(5), EvenUsize(6)];
+
+    let foo = vet_all_refs(&buffer[..2]).unwrap();
+    assert_eq!(*foo, &[EvenUsize(2), EvenUsize(4)]);
+
+    assert!(vet_all_refs(&buffer).is_err());
+    assert!(vet_all_refs::<EvenUsize>(&[]).is_ok());
+}
+
+#[test]
+fn valid_from_ref() {
+    let foo = EvenUsize(14);
+    assert!(foo.is_valid().is_ok());
+
+    // SAFETY: `foo` was validated above and is not mutated.
+    let valid = unsafe { Valid::from_ref(&foo) };
+    assert_eq!(**valid, foo);
+    assert!(core::ptr::eq(&**valid, &foo));
+}
+
+#[test]
+fn take_valid() {
+    let mut foo = EvenUsize(20).vet().unwrap();
+    let taken = foo.take();
+    assert_eq!(*taken, EvenUsize(20));
+    assert_eq!(*foo, EvenUsize(0));
+}
+
+#[test]
+#[should_panic]
+fn take_valid_invalid_default() {
+    #[derive(Default)]
+    struct NonZero(usize);
+
+    impl Vet for NonZero {
+        type Error = ();
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0 == 0 {
+                Err(())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let mut foo = NonZero(1).vet().unwrap();
+    foo.take();
+}
+
+#[test]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+fn vet_arc() {
+    use alloc::sync::Arc;
+
+    let foo = Arc::new(EvenUsize(2));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = Arc::new(EvenUsize(3));
+    assert!(foo.is_valid().is_err());
+
+    let foo = Arc::new(alloc::vec![EvenUsize(1), EvenUsize(2), EvenUsize(3)]);
+    assert_eq!(foo.vet_mode(VetMode::AllErrors).unwrap_err().len(), 2);
+
+    let foo = Arc::new(alloc::vec![EvenUsize(2), EvenUsize(3)]);
+    let mut path = alloc::vec::Vec::new();
+    assert!(foo.is_valid_at(&mut path).is_err());
+    assert_eq!(path, [PathSegment::Index(1)]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn validate_all_erased() {
+    use alloc::format;
+
+    #[derive(Debug)]
+    struct Name(&'static str);
+
+    #[derive(Debug)]
+    struct EmptyName;
+
+    impl Vet for Name {
+        type Error = EmptyName;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0.is_empty() {
+                Err(EmptyName)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    assert!(validate_all(&[&EvenUsize(2), &Name("vet"), &EvenUsize(4)]).is_ok());
+
+    let error = validate_all(&[&EvenUsize(2), &Name(""), &EvenUsize(5)]).unwrap_err();
+    assert_eq!(format!("{:?}", error), "EmptyName");
+
+    let error = validate_all(&[&EvenUsize(3), &Name("")]).unwrap_err();
+    assert_eq!(format!("{:?}", error), "OddUsize");
+}
+
+#[test]
+fn inner_eq_by_valid() {
+    #[derive(Debug, PartialEq)]
+    struct Tag(&'static str);
+
+    impl Vet for Tag {
+        type Error = ();
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0.is_empty() {
+                Err(())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let lower = Tag("rust").vet().unwrap();
+    let upper = Tag("RUST").vet().unwrap();
+    let other = Tag("vet").vet().unwrap();
+
+    assert_ne!(lower, upper);
+    assert!(lower.inner_eq_by(&upper, |a, b| a.0.eq_ignore_ascii_case(b.0)));
+    assert!(!lower.inner_eq_by(&other, |a, b| a.0.eq_ignore_ascii_case(b.0)));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_with_warnings_detailed() {
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Debug, PartialEq)]
+    struct Password(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    enum InvalidPassword {
+        Empty,
+        NoDigit,
+        NoUppercase,
+    }
+
+    impl Vet for Password {
+        type Error = InvalidPassword;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0.is_empty() {
+                Err(InvalidPassword::Empty)
+            } else {
+                Ok(())
+            }
+        }
+        fn is_valid_detailed(&self) -> Vec<(Severity, Self::Error)> {
+            let mut failures = Vec::new();
+            if self.0.is_empty() {
+                failures.push((Severity::Error, InvalidPassword::Empty));
+            }
+            if !self.0.chars().any(|c| c.is_ascii_digit()) {
+                failures.push((Severity::Warning, InvalidPassword::NoDigit));
+            }
+            if !self.0.chars().any(|c| c.is_ascii_uppercase()) {
+                failures.push((Severity::Warning, InvalidPassword::NoUppercase));
+            }
+            failures
+        }
+    }
+
+    let (password, warnings) = Password("hunter").vet_with_warnings().unwrap();
+    assert_eq!(*password, Password("hunter"));
+    assert!(password.recheck().is_ok());
+    assert_eq!(
+        warnings,
+        vec![InvalidPassword::NoDigit, InvalidPassword::NoUppercase]
+    );
+
+    let (_, warnings) = Password("Hunter2").vet_with_warnings().unwrap();
+    assert!(warnings.is_empty());
+
+    let error = Password("").vet_with_warnings().unwrap_err();
+    assert_eq!(error, InvalidPassword::Empty);
+
+    let error = EvenUsize(3).vet_with_warnings().unwrap_err();
+    assert!(matches!(error, OddUsize));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_with_warnings_requires_is_valid() {
+    struct Inconsistent;
+
+    #[derive(Debug, PartialEq)]
+    struct Failure;
+
+    impl Vet for Inconsistent {
+        type Error = Failure;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Err(Failure)
+        }
+        fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+            alloc::vec![(Severity::Warning, Failure)]
+        }
+    }
+
+    assert!(matches!(Inconsistent.vet_with_warnings(), Err(Failure)));
+}
+
+#[test]
+fn vet_infallible_error() {
+    use core::convert::Infallible;
+
+    #[derive(Debug, PartialEq)]
+    struct AnyUsize(usize);
+
+    impl Vet for AnyUsize {
+        type Error = Infallible;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    fn vet_total<T: Vet<Error = Infallible>>(value: T) -> Valid<T> {
+        value.vet_infallible()
+    }
+
+    assert_eq!(*vet_total(AnyUsize(3)), AnyUsize(3));
+    assert_eq!(*vet_total(Some(AnyUsize(5))), Some(AnyUsize(5)));
+    assert!(vet_total(None::<Infallible>).is_none());
+}
+
+#[test]
+fn as_pinned_valid() {
+    let foo = EvenUsize(30).vet().unwrap();
+    let pinned = foo.as_pinned();
+    assert_eq!(*pinned, EvenUsize(30));
+    assert!(core::ptr::eq(pinned.get_ref(), &*foo));
+}
+
+#[test]
+fn vet_slice() {
+    let foo: &[EvenUsize] = &[];
+    assert!(foo.is_valid().is_ok());
+
+    let foo: &[EvenUsize] = &[EvenUsize(2), EvenUsize(4)];
+    assert!(foo.is_valid().is_ok());
+
+    let foo: &[EvenUsize] = &[EvenUsize(2), EvenUsize(3)];
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+fn vet_rev_slice() {
+    #[derive(Debug, PartialEq)]
+    struct Odd(usize);
+
+    #[derive(Debug)]
+    struct Item(usize);
+
+    impl Vet for Item {
+        type Error = Odd;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0 % 2 == 0 {
+                Ok(())
+            } else {
+                Err(Odd(self.0))
+            }
+        }
+    }
+
+    let foo = [Item(1), Item(2), Item(3), Item(4)];
+    assert_eq!(foo.is_valid().unwrap_err(), Odd(1));
+    assert_eq!(foo.vet_rev().unwrap_err(), Odd(3));
+
+    let foo = [Item(2), Item(4)];
+    assert_eq!(foo.vet_rev().unwrap().len(), 2);
+}
+
+#[test]
+fn from_validated_parts_valid() {
+    #[derive(Debug, PartialEq)]
+    struct Pair {
+        left: EvenUsize,
+        right: EvenUsize,
+    }
+
+    let left = EvenUsize(2).vet().unwrap();
+    let right = EvenUsize(6).vet().unwrap();
+
+    let pair = Valid::from_validated_parts(left, right, |left, right| Pair { left, right });
+    assert_eq!(
+        *pair,
+        Pair {
+            left: EvenUsize(2),
+            right: EvenUsize(6),
+        }
+    );
+}
+
+#[test]
+fn vet_finite() {
+    assert!(Finite(1.5f32).is_valid().is_ok());
+    assert_eq!(Finite(f32::NAN).is_valid(), Err(NotFinite));
+    assert_eq!(Finite(f32::NEG_INFINITY).is_valid(), Err(NotFinite));
+
+    assert!(Finite(-2.25f64).is_valid().is_ok());
+    assert_eq!(Finite(f64::NAN).is_valid(), Err(NotFinite));
+    assert_eq!(Finite(f64::INFINITY).is_valid(), Err(NotFinite));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_hash_map() {
+    use std::collections::HashMap;
+
+    let foo = HashMap::from([("a", EvenUsize(2)), ("b", EvenUsize(4))]);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = HashMap::from([("a", EvenUsize(2)), ("b", EvenUsize(5))]);
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_hash_map_keys() {
+    use std::collections::HashMap;
+
+    let foo = HashMap::from([(EvenUsize(2), EvenUsize(4)), (EvenUsize(6), EvenUsize(8))]);
+    assert!(foo.vet_keys().is_ok());
+    assert!(foo.vet_entries().is_ok());
+
+    let foo = HashMap::from([(EvenUsize(3), EvenUsize(4))]);
+    assert!(foo.is_valid().is_ok());
+    assert!(foo.vet_keys().is_err());
+    assert!(matches!(foo.vet_entries(), Err(EntryError::Key(OddUsize))));
+
+    let foo = HashMap::from([(EvenUsize(2), EvenUsize(5))]);
+    assert!(foo.vet_keys().is_ok());
+    assert!(matches!(
+        foo.vet_entries(),
+        Err(EntryError::Value(OddUsize))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn into_shared_valid() {
+    let foo = EvenUsize(40).vet().unwrap().into_shared();
+    let bar = foo.clone();
+    assert_eq!(**foo, EvenUsize(40));
+    assert!(alloc::rc::Rc::ptr_eq(&foo, &bar));
+}
+
+#[test]
+fn vet_all_of_checks() {
+    fn nonzero(e: &EvenUsize) -> Result<(), OddUsize> {
+        if e.0 == 0 {
+            Err(OddUsize)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn small(e: &EvenUsize) -> Result<(), OddUsize> {
+        if e.0 < 100 {
+            Ok(())
+        } else {
+            Err(OddUsize)
+        }
+    }
+
+    fn multiple_of_four(e: &EvenUsize) -> Result<(), OddUsize> {
+        if e.0 % 4 == 0 {
+            Ok(())
+        } else {
+            Err(OddUsize)
+        }
+    }
+
+    assert!(EvenUsize(8).vet_all_of([nonzero, small]).is_ok());
+    assert!(EvenUsize(6)
+        .vet_all_of([nonzero, small, multiple_of_four])
+        .is_err());
+    assert!(EvenUsize(8)
+        .vet_all_of([nonzero, small, multiple_of_four])
+        .is_ok());
+    assert!(EvenUsize(9).vet_all_of([nonzero]).is_err());
+}
+
+#[test]
+fn vet_byte_and_char_len() {
+    // Two characters, six bytes
+    let name = "日向";
+
+    assert_eq!(
+        ByteLen::<_, 3, 4>(name).is_valid(),
+        Err(LengthError::TooLong)
+    );
+    assert!(CharLen::<_, 1, 4>(name).is_valid().is_ok());
+
+    assert!(ByteLen::<_, 3, 8>(name).is_valid().is_ok());
+    assert_eq!(
+        CharLen::<_, 3, 8>(name).is_valid(),
+        Err(LengthError::TooShort)
+    );
+
+    assert!(ByteLen::<_, 0, 5>("hello").is_valid().is_ok());
+    assert!(CharLen::<_, 0, 5>("hello").is_valid().is_ok());
+}
+
+#[test]
+fn ensure_valid() {
+    #[derive(Debug, PartialEq)]
+    struct TooLarge;
+
+    let foo = EvenUsize(8).vet().unwrap();
+    let foo = foo.ensure(|e| e.0 <= 10, TooLarge).unwrap();
+    assert_eq!(*foo, EvenUsize(8));
+
+    let foo = EvenUsize(12).vet().unwrap();
+    assert_eq!(foo.ensure(|e| e.0 <= 10, TooLarge), Err(TooLarge));
+}
+
+#[test]
+fn vet_transparent() {
+    #[derive(Debug)]
+    struct Wrapper(EvenUsize);
+
+    impl Transparent for Wrapper {
+        type Inner = EvenUsize;
+        fn inner(&self) -> &Self::Inner {
+            &self.0
+        }
+    }
+
+    assert!(Wrapper(EvenUsize(2)).is_valid().is_ok());
+    assert!(Wrapper(EvenUsize(3)).vet().is_err());
+    assert_eq!(
+        Wrapper(EvenUsize(4)).vet().unwrap().into_inner().0,
+        EvenUsize(4)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_path() {
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Debug)]
+    struct User {
+        email: EvenUsize,
+    }
+
+    impl Vet for User {
+        type Error = OddUsize;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            self.email.is_valid()
+        }
+        fn is_valid_at(&self, path: &mut Vec<PathSegment>) -> Result<(), Self::Error> {
+            path.push(PathSegment::Field("email"));
+            self.email.is_valid_at(path)?;
+            path.pop();
+            Ok(())
+        }
+    }
+
+    struct Directory {
+        users: Vec<User>,
+    }
+
+    impl Vet for Directory {
+        type Error = OddUsize;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            self.users.is_valid()
+        }
+        fn is_valid_at(&self, path: &mut Vec<PathSegment>) -> Result<(), Self::Error> {
+            path.push(PathSegment::Field("users"));
+            self.users.is_valid_at(path)?;
+            path.pop();
+            Ok(())
+        }
+    }
+
+    let directory = Directory {
+        users: [2, 4, 6, 7, 8]
+            .into_iter()
+            .map(|i| User {
+                email: EvenUsize(i),
+            })
+            .collect(),
+    };
+
+    let mut path = Vec::new();
+    assert!(directory.is_valid_at(&mut path).is_err());
+    assert_eq!(
+        path,
+        vec![
+            PathSegment::Field("users"),
+            PathSegment::Index(3),
+            PathSegment::Field("email"),
+        ]
+    );
+    let path: Vec<_> = path
+        .iter()
+        .map(alloc::string::ToString::to_string)
+        .collect();
+    assert_eq!(path, ["users", "3", "email"]);
+
+    let directory = Directory { users: Vec::new() };
+    let mut path = Vec::new();
+    assert!(directory.is_valid_at(&mut path).is_ok());
+    assert!(path.is_empty());
+}
+
+#[test]
+fn iter_valid() {
+    let foo = EvenUsize(36).vet().unwrap();
+    let mut iter = foo.iter_once();
+    assert_eq!(iter.next(), Some(&EvenUsize(36)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn iter_valid_collection_elements() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4), EvenUsize(6)]
+        .vet()
+        .unwrap();
+    assert_eq!(foo.iter().count(), 3);
+
+    let foo = vet_all_refs(&[EvenUsize(2), EvenUsize(4)]).unwrap();
+    assert_eq!(foo.iter().count(), 2);
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn vet_bytes() {
+    use bytes::{Bytes, BytesMut};
+
+    assert_eq!(
+        NonEmptyBytes(Bytes::new()).is_valid(),
+        Err(LengthError::TooShort)
+    );
+    assert!(NonEmptyBytes(Bytes::from_static(b"vet")).is_valid().is_ok());
+
+    assert!(MaxBytes::<_, 4>(Bytes::from_static(b"vet"))
+        .is_valid()
+        .is_ok());
+    assert!(MaxBytes::<_, 3>(Bytes::from_static(b"vet"))
+        .is_valid()
+        .is_ok());
+    assert_eq!(
+        MaxBytes::<_, 2>(Bytes::from_static(b"vet")).is_valid(),
+        Err(LengthError::TooLong)
+    );
+
+    let buffer = BytesMut::from(&b"validate"[..]);
+    assert!(NonEmptyBytes(buffer.clone()).is_valid().is_ok());
+    assert_eq!(
+        MaxBytes::<_, 4>(buffer).is_valid(),
+        Err(LengthError::TooLong)
+    );
+}
+
+#[test]
+fn verify_invariant_valid() {
+    let foo = EvenUsize(4).vet().unwrap();
+    let foo = foo.verify_invariant().unwrap();
+    assert_eq!(*foo, EvenUsize(4));
+
+    let mut foo = EvenUsize(4).vet().unwrap();
+    // SAFETY: The invalid value is only observed through `verify_invariant`.
+    unsafe { foo.dangerous_get_mut().0 = 5 };
+    assert!(foo.verify_invariant().is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_mode_vec() {
+    use alloc::vec;
+
+    let foo = vec![EvenUsize(1), EvenUsize(2), EvenUsize(3)];
+    assert_eq!(foo.vet_mode(VetMode::FirstError).unwrap_err().len(), 1);
+
+    let foo = vec![EvenUsize(1), EvenUsize(2), EvenUsize(3)];
+    assert_eq!(foo.vet_mode(VetMode::AllErrors).unwrap_err().len(), 2);
+
+    let foo = [EvenUsize(5), EvenUsize(7), EvenUsize(9)];
+    assert_eq!(foo.vet_mode(VetMode::AllErrors).unwrap_err().len(), 3);
+
+    let foo = vec![EvenUsize(2), EvenUsize(4)];
+    assert!(foo.vet_mode(VetMode::FirstError).is_ok());
+
+    let foo = vec![EvenUsize(2), EvenUsize(4)];
+    assert!(foo.vet_mode(VetMode::AllErrors).is_ok());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_mode_agrees() {
+    struct Inconsistent;
+
+    #[derive(Debug, PartialEq)]
+    struct Failure;
+
+    impl Vet for Inconsistent {
+        type Error = Failure;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Err(Failure)
+        }
+        fn is_valid_detailed(&self) -> alloc::vec::Vec<(Severity, Self::Error)> {
+            alloc::vec![(Severity::Warning, Failure)]
+        }
+    }
+
+    assert_eq!(
+        Inconsistent.vet_mode(VetMode::FirstError).err(),
+        Some(alloc::vec![Failure])
+    );
+    assert_eq!(
+        Inconsistent.vet_mode(VetMode::AllErrors).err(),
+        Some(alloc::vec![Failure])
+    );
+}
+
+#[test]
+fn vet_well_formed_inclusive() {
+    let foo = WellFormedInclusive(3..=3);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = WellFormedInclusive(1..=10);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = WellFormedInclusive(core::ops::RangeInclusive::new(10, 1));
+    assert_eq!(foo.is_valid(), Err(StartAfterEnd));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_debug_err_type() {
+    assert!(EvenUsize(2).vet_debug_err().is_ok());
+
+    let error = EvenUsize(9).vet_debug_err().unwrap_err();
+    assert!(matches!(error.error, OddUsize));
+    assert_eq!(error.debug, "EvenUsize(9)");
+}
+
+#[test]
+fn vet_array_into_error() {
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        Odd,
+        Missing,
+    }
+
+    impl From<OddUsize> for ConfigError {
+        fn from(_: OddUsize) -> Self {
+            ConfigError::Odd
+        }
+    }
+
+    impl From<RequiredError<OddUsize>> for ConfigError {
+        fn from(e: RequiredError<OddUsize>) -> Self {
+            match e {
+                RequiredError::Missing => ConfigError::Missing,
+                RequiredError::Invalid(e) => e.into(),
+            }
+        }
+    }
+
+    let foo = vet_array_into::<_, ConfigError, 2>([EvenUsize(2), EvenUsize(4)]);
+    assert!(foo.is_ok());
+
+    let foo = vet_array_into::<_, ConfigError, 2>([EvenUsize(2), EvenUsize(3)]);
+    assert_eq!(foo.unwrap_err(), ConfigError::Odd);
+
+    let foo = vet_array_into::<_, ConfigError, 2>([Required(Some(EvenUsize(2))), Required(None)]);
+    assert_eq!(foo.unwrap_err(), ConfigError::Missing);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_reader_cursor() {
+    use std::io::Cursor;
+
+    fn parse(
+        input: &[u8],
+    ) -> Result<EvenUsize, std::boxed::Box<dyn std::error::Error + Send + Sync>> {
+        Ok(EvenUsize(core::str::from_utf8(input)?.trim().parse()?))
+    }
+
+    let foo = vet_reader(Cursor::new("42\n"), parse).unwrap();
+    assert_eq!(*foo, EvenUsize(42));
+
+    let foo = vet_reader(Cursor::new("forty-two"), parse);
+    assert!(matches!(foo, Err(VetReadError::Parse(_))));
+
+    let foo = vet_reader(Cursor::new("43"), parse);
+    assert!(matches!(foo, Err(VetReadError::Invalid(OddUsize))));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn into_shared_sync_valid() {
+    let foo = EvenUsize(50).vet().unwrap().into_shared_sync();
+    let bar = foo.clone();
+    let handle = std::thread::spawn(move || bar.into_inner().0);
+    assert_eq!(handle.join().unwrap(), 50);
+    assert_eq!(**foo, EvenUsize(50));
+}
+
+#[test]
+fn vet_require_ok() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct ParseError;
+
+    let foo = RequireOk(Err::<EvenUsize, _>(ParseError));
+    assert!(matches!(
+        foo.is_valid(),
+        Err(RequireOkError::Err(ParseError))
+    ));
+
+    let foo = RequireOk(Ok::<_, ParseError>(EvenUsize(2)));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = RequireOk(Ok::<_, ParseError>(EvenUsize(3)));
+    assert!(matches!(
+        foo.is_valid(),
+        Err(RequireOkError::Invalid(OddUsize))
+    ));
+}
+
+#[derive(Debug)]
+struct Temperature(i16);
+
+#[derive(Debug, PartialEq)]
+struct OutOfRange;
+
+impl_ranged_vet!(Temperature, -40..=50, OutOfRange);
+
+#[test]
+fn vet_ranged_macro() {
+    assert_eq!(Temperature(-41).is_valid(), Err(OutOfRange));
+    assert!(Temperature(-40).is_valid().is_ok());
+    assert!(Temperature(21).is_valid().is_ok());
+    assert!(Temperature(50).is_valid().is_ok());
+    assert_eq!(Temperature(51).is_valid(), Err(OutOfRange));
+}
+
+mod ranged_macro_hygiene {
+    use crate::Vet;
+
+    #[allow(dead_code)]
+    type Result<T> = core::result::Result<T, ()>;
+
+    pub struct Percent(pub u8);
+
+    #[derive(Debug, PartialEq)]
+    pub struct OverHundred;
+
+    crate::impl_ranged_vet!(Percent, 0..=100, OverHundred);
+
+    #[test]
+    fn vet_ranged_macro_with_result_alias() {
+        assert!(Percent(100).is_valid().is_ok());
+        assert_eq!(Percent(101).is_valid(), Err(OverHundred));
+    }
+}
+
+#[test]
+fn flatten_option_valid() {
+    let foo = Some(EvenUsize(84).vet().unwrap()).flatten_option();
+    assert_eq!(*foo, Some(EvenUsize(84)));
+    assert_eq!(foo.transpose(), Some(EvenUsize(84).vet().unwrap()));
+
+    let foo = None::<Valid<EvenUsize>>.flatten_option();
+    assert_eq!(*foo, None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_into_type() {
+    use alloc::string::String;
+
+    #[derive(Debug, PartialEq)]
+    struct Slug(String);
+
+    #[derive(Debug, PartialEq)]
+    struct InvalidSlug;
+
+    impl From<String> for Slug {
+        fn from(s: String) -> Self {
+            Slug(s)
+        }
+    }
+
+    impl Vet for Slug {
+        type Error = InvalidSlug;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if !self.0.is_empty() && self.0.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+                Ok(())
+            } else {
+                Err(InvalidSlug)
+            }
+        }
+    }
+
+    let foo: Valid<Slug> = String::from("hello-world").vet_into().unwrap();
+    assert_eq!(*foo, Slug(String::from("hello-world")));
+
+    let foo = String::from("Hello World").vet_into::<Slug>();
+    assert_eq!(foo, Err(InvalidSlug));
+}
+
+#[test]
+fn validation_cost_hint() {
+    struct Document;
+
+    impl Vet for Document {
+        type Error = ();
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+        fn validation_cost(&self) -> ValidationCost {
+            ValidationCost::Expensive
+        }
+    }
+
+    assert_eq!(EvenUsize(2).validation_cost(), ValidationCost::Cheap);
+    assert_eq!(Document.validation_cost(), ValidationCost::Expensive);
+    assert_eq!(Vet::validation_cost(&&Document), ValidationCost::Expensive);
+}
+
+#[test]
+fn vet_tuple() {
+    let foo = (EvenUsize(2), Finite(1.0f64));
+    assert!(foo.is_valid().is_ok());
+
+    let foo = (EvenUsize(3), Finite(1.0f64));
+    assert!(matches!(foo.is_valid(), Err(Tuple2Error::A(OddUsize))));
+
+    let foo = (EvenUsize(2), Finite(f64::NAN));
+    assert!(matches!(foo.is_valid(), Err(Tuple2Error::B(NotFinite))));
+
+    let foo = (EvenUsize(2), Finite(1.0f32), WellFormedRange(0..1));
+    assert!(foo.vet().is_ok());
+}
+
+#[test]
+fn vet_any_of_checks() {
+    #[derive(Debug, PartialEq)]
+    struct Code(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    enum InvalidCode {
+        Empty,
+        NotNumeric,
+        NotHex,
+        NotBinary,
+    }
+
+    impl Vet for Code {
+        type Error = InvalidCode;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0.is_empty() {
+                Err(InvalidCode::Empty)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn numeric(c: &Code) -> Result<(), InvalidCode> {
+        match c.0.chars().all(|c| c.is_ascii_digit()) {
+            true => Ok(()),
+            false => Err(InvalidCode::NotNumeric),
+        }
+    }
+
+    fn hex(c: &Code) -> Result<(), InvalidCode> {
+        match c.0.chars().all(|c| c.is_ascii_hexdigit()) {
+            true => Ok(()),
+            false => Err(InvalidCode::NotHex),
+        }
+    }
+
+    fn binary(c: &Code) -> Result<(), InvalidCode> {
+        match c.0.chars().all(|c| c == '0' || c == '1') {
+            true => Ok(()),
+            false => Err(InvalidCode::NotBinary),
+        }
+    }
+
+    let foo = Code("beef").vet_any_of([numeric, hex, binary]).unwrap();
+    assert_eq!(*foo, Code("beef"));
+
+    let foo = Code("xyz").vet_any_of([numeric, hex, binary]);
+    assert_eq!(
+        foo,
+        Err(AnyOfError::NoneMatched([
+            InvalidCode::NotNumeric,
+            InvalidCode::NotHex,
+            InvalidCode::NotBinary,
+        ]))
+    );
+
+    let foo = Code("").vet_any_of([numeric, hex, binary]);
+    assert_eq!(foo, Err(AnyOfError::Invalid(InvalidCode::Empty)));
+}
+
+#[test]
+fn valid_with_clone() {
+    let foo = EvenUsize(2).vet().unwrap();
+
+    let bar = foo.with(|v| v.0 += 2).unwrap();
+    assert_eq!(*bar, EvenUsize(4));
+    assert_eq!(*foo, EvenUsize(2));
+
+    assert!(foo.with(|v| v.0 += 1).is_err());
+    assert_eq!(*foo, EvenUsize(2));
+}
+
+#[cfg(all(feature = "indexmap", feature = "std"))]
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Status(u8);
+
+#[cfg(all(feature = "indexmap", feature = "std"))]
+impl Vet for Status {
+    type Error = u8;
+    fn is_valid(&self) -> Result<(), Self::Error> {
+        match self.0 {
+            0 => Ok(()),
+            code => Err(code),
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "indexmap", feature = "std"))]
+fn vet_indexmap() {
+    let foo = indexmap::IndexMap::from([("a", Status(0)), ("b", Status(3)), ("c", Status(5))]);
+    assert_eq!(foo.is_valid(), Err(3));
+
+    let foo = indexmap::IndexMap::from([("a", Status(0)), ("b", Status(0))]);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = indexmap::IndexMap::from([(Status(0), Status(0)), (Status(7), Status(2))]);
+    assert_eq!(foo.vet_keys(), Err(7));
+    assert_eq!(foo.vet_entries(), Err(EntryError::Key(7)));
+}
+
+#[test]
+#[cfg(all(feature = "indexmap", feature = "std"))]
+fn vet_indexset() {
+    let foo = indexmap::IndexSet::from([Status(0), Status(4), Status(1)]);
+    assert_eq!(foo.is_valid(), Err(4));
+
+    let foo = indexmap::IndexSet::from([Status(0)]);
+    assert!(foo.is_valid().is_ok());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn valid_vec_checked_index() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4)].vet().unwrap();
+
+    let index = foo.checked_index(1).unwrap();
+    assert_eq!(index.index(), 1);
+    assert_eq!(index.get(), &EvenUsize(4));
+
+    assert!(foo.checked_index(2).is_none());
+}
+
+#[test]
+#[should_panic(expected = "`EvenUsize(3)` is not valid: OddUsize")]
+fn assert_valid_panics() {
+    assert_valid!(EvenUsize(3));
+}
+
+#[test]
+fn valid_project() {
+    struct Record {
+        a: usize,
+        b: usize,
+    }
+
+    impl Vet for Record {
+        type Error = OddUsize;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            EvenUsize(self.a).is_valid()
+        }
+    }
+
+    let record = Record { a: 2, b: 4 }.vet().unwrap();
+
+    let summary = record.project(|r| EvenUsize(r.a + r.b)).unwrap();
+    assert_eq!(*summary, EvenUsize(6));
+    assert_eq!(record.a, 2);
+
+    assert!(record.project(|r| EvenUsize(r.a + 1)).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_vec_len_range() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4)].vet_len_range(1..=3);
+    assert_eq!(foo.unwrap().len(), 2);
+
+    let foo = alloc::vec::Vec::<EvenUsize>::new().vet_len_range(1..=3);
+    assert!(matches!(
+        foo,
+        Err(LenOrElemError::Len(LengthError::TooShort))
+    ));
+
+    let foo = alloc::vec![EvenUsize(2); 4].vet_len_range(1..=3);
+    assert!(matches!(
+        foo,
+        Err(LenOrElemError::Len(LengthError::TooLong))
+    ));
+
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(3)].vet_len_range(1..=3);
+    assert!(matches!(foo, Err(LenOrElemError::Elem(OddUsize))));
+}
+
+#[test]
+fn vet_bound() {
+    use core::ops::Bound;
+
+    assert!(Bound::Included(EvenUsize(2)).is_valid().is_ok());
+    assert!(Bound::Included(EvenUsize(3)).is_valid().is_err());
+
+    assert!(Bound::Excluded(EvenUsize(2)).is_valid().is_ok());
+    assert!(Bound::Excluded(EvenUsize(3)).is_valid().is_err());
+
+    assert!(Bound::<EvenUsize>::Unbounded.is_valid().is_ok());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_collect_into_collections() {
+    use alloc::collections::{BTreeSet, VecDeque};
+
+    let foo: Valid<BTreeSet<EvenUsize>> = vet_collect_into([4, 2, 4].map(EvenUsize)).unwrap();
+    assert_eq!(
+        foo.into_inner(),
+        BTreeSet::from([EvenUsize(2), EvenUsize(4)])
+    );
+
+    let foo: Result<Valid<VecDeque<EvenUsize>>, _> = vet_collect_into([2, 3].map(EvenUsize));
+    assert!(foo.is_err());
+
+    let foo: Valid<VecDeque<EvenUsize>> = vet_collect_into([2, 6].map(EvenUsize)).unwrap();
+    assert_eq!(
+        foo.into_inner(),
+        VecDeque::from([EvenUsize(2), EvenUsize(6)])
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sort_valid_matches_inner() {
+    let raw = [6, 2, 8, 4, 2];
+    let mut expected = raw.map(EvenUsize);
+    expected.sort();
+
+    let mut foo: alloc::vec::Vec<_> = raw.iter().map(|&n| EvenUsize(n).vet().unwrap()).collect();
+    foo.sort();
+    assert!(foo.iter().map(|v| &**v).eq(&expected));
+
+    foo.reverse();
+    sort_valid(&mut foo);
+    assert!(foo.iter().map(|v| &**v).eq(&expected));
+}
+
+#[test]
+fn vet_reverse() {
+    use core::cmp::Reverse;
+
+    assert!(Reverse(EvenUsize(2)).is_valid().is_ok());
+    assert!(Reverse(EvenUsize(3)).is_valid().is_err());
+
+    let foo = Reverse(EvenUsize(4)).vet().unwrap();
+    assert!(*foo < Reverse(EvenUsize(2)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_snapshot() {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    let foo = Arc::new(Mutex::new(EvenUsize(2)));
+    assert!(SnapshotVet(&*foo).is_valid().is_ok());
+
+    *foo.lock().unwrap() = EvenUsize(3);
+    assert!(matches!(
+        SnapshotVet(&*foo).is_valid(),
+        Err(SnapshotError::Invalid(OddUsize))
+    ));
+
+    let bar = Arc::clone(&foo);
+    let _ = std::thread::spawn(move || {
+        let _guard = bar.lock().unwrap();
+        panic!("poison the lock");
+    })
+    .join();
+    assert!(matches!(
+        SnapshotVet(&*foo).is_valid(),
+        Err(SnapshotError::Poisoned)
+    ));
+
+    let foo = RwLock::new(EvenUsize(4));
+    assert!(SnapshotVet(&foo).is_valid().is_ok());
+
+    *foo.write().unwrap() = EvenUsize(5);
+    assert!(matches!(
+        SnapshotVet(&foo).is_valid(),
+        Err(SnapshotError::Invalid(OddUsize))
+    ));
+}
+
+#[test]
+fn valid_into_validator() {
+    let foo = Valid::into_validator(EvenUsize(2));
+    let bar = Valid::into_validator(EvenUsize(3));
+
+    assert_eq!(*foo().unwrap(), EvenUsize(2));
+    assert!(bar().is_err());
+}
+
+#[test]
+fn vet_cstr() {
+    assert!(Utf8CStr(c"héllo").is_valid().is_ok());
+    assert_eq!(Utf8CStr(c"\xff\xfe").is_valid(), Err(NotUtf8));
+
+    assert!(CStrLen::<_, 1, 5>(c"hello").is_valid().is_ok());
+    assert_eq!(
+        CStrLen::<_, 1, 4>(c"hello").is_valid(),
+        Err(LengthError::TooLong)
+    );
+    assert_eq!(
+        CStrLen::<_, 1, 4>(c"").is_valid(),
+        Err(LengthError::TooShort)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_cstring() {
+    let foo = alloc::ffi::CString::new("hello").unwrap();
+    assert!(Utf8CStr(&foo).is_valid().is_ok());
+    assert!(CStrLen::<_, 0, 8>(foo).is_valid().is_ok());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_normalized() {
+    use alloc::string::String;
+
+    #[derive(Debug, PartialEq)]
+    struct Email(String);
+
+    #[derive(Debug, PartialEq)]
+    struct InvalidEmail;
+
+    impl Vet for Email {
+        type Error = InvalidEmail;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            let lowercase = !self.0.chars().any(char::is_uppercase);
+            if lowercase && self.0.contains('@') {
+                Ok(())
+            } else {
+                Err(InvalidEmail)
+            }
+        }
+    }
+
+    let lowercase = |e: &Email| Email(e.0.trim().to_lowercase());
+
+    let foo = Email(String::from(" Alice@Example.com "));
+    assert_eq!(foo.is_valid(), Err(InvalidEmail));
+
+    let foo = Normalized(foo, lowercase).vet().unwrap();
+    assert_eq!(
+        foo.into_normalized().into_inner(),
+        Email(String::from("alice@example.com"))
+    );
+
+    let foo = Normalized(Email(String::from("Alice")), lowercase);
+    assert_eq!(foo.is_valid(), Err(InvalidEmail));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn valid_key_in_map() {
+    let mut foo = std::collections::HashMap::new();
+    foo.insert(ValidKey::from(EvenUsize(2).vet().unwrap()), "two");
+    foo.insert(ValidKey::from(EvenUsize(4).vet().unwrap()), "four");
+
+    assert_eq!(foo.get(&EvenUsize(2)), Some(&"two"));
+    assert_eq!(foo.get(&EvenUsize(3)), None);
+
+    let key = foo.into_keys().min().unwrap();
+    assert_eq!(key.into_inner().into_inner(), EvenUsize(2));
+}
+
+#[test]
+fn vet_control_flow() {
+    use core::ops::ControlFlow;
+
+    assert!(ControlFlow::<EvenUsize, EvenUsize>::Continue(EvenUsize(2))
+        .is_valid()
+        .is_ok());
+    assert!(ControlFlow::<EvenUsize, EvenUsize>::Continue(EvenUsize(3))
+        .is_valid()
+        .is_err());
+
+    assert!(ControlFlow::<EvenUsize, EvenUsize>::Break(EvenUsize(4))
+        .is_valid()
+        .is_ok());
+    assert!(ControlFlow::<EvenUsize, EvenUsize>::Break(EvenUsize(5))
+        .is_valid()
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vet_timeout_expires() {
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct Slow(Duration);
+
+    impl Vet for Slow {
+        type Error = ();
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            std::thread::sleep(self.0);
+            Ok(())
+        }
+    }
+
+    let foo = Slow(Duration::from_secs(5)).vet_timeout(Duration::from_millis(10));
+    assert!(matches!(foo, Err(TimeoutOrError::Timeout)));
+
+    let foo = Slow(Duration::ZERO).vet_timeout(Duration::from_secs(5));
+    assert!(foo.is_ok());
+
+    let foo = EvenUsize(3).vet_timeout(Duration::from_secs(5));
+    assert!(matches!(foo, Err(TimeoutOrError::Invalid(OddUsize))));
+}
+
+#[test]
+fn static_str_error_conversion() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<StaticStrError>();
+
+    let foo = vet_array_into::<_, StaticStrError, 2>([
+        WellFormedRange(1..2),
+        WellFormedRange(core::ops::Range { start: 8, end: 2 }),
+    ]);
+    assert_eq!(foo, Err(StaticStrError("range start is after its end")));
+
+    let foo = StaticStrError::from(LengthError::TooLong);
+    assert_eq!(foo.message(), "length is too long");
+
+    let foo = StaticStrError::from("custom");
+    assert_eq!(foo, StaticStrError("custom"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn static_str_error_display() {
+    let foo = StaticStrError::from(NotFinite);
+    assert_eq!(alloc::format!("{}", foo), "number is not finite");
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "std"))]
+fn valid_serde_reports_error() {
+    #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    #[serde(transparent)]
+    struct Port(u16);
+
+    #[derive(Debug)]
+    struct ReservedPort(u16);
+
+    impl core::fmt::Display for ReservedPort {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "port {} is reserved", self.0)
+        }
+    }
+
+    impl Vet for Port {
+        type Error = ReservedPort;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0 < 1024 {
+                Err(ReservedPort(self.0))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let foo: Valid<Port> = serde_json::from_str("8080").unwrap();
+    assert_eq!(serde_json::to_string(&foo).unwrap(), "8080");
+
+    let foo = serde_json::from_str::<Valid<Port>>("80").unwrap_err();
+    assert!(foo.to_string().contains("port 80 is reserved"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_vec_progress() {
+    let total = PROGRESS_CHUNK * 2 + 3;
+    let mut calls = alloc::vec::Vec::new();
+    let foo =
+        alloc::vec![EvenUsize(2); total].vet_progress(|done, total| calls.push((done, total)));
+    assert!(foo.is_ok());
+    assert_eq!(
+        calls,
+        [
+            (PROGRESS_CHUNK, total),
+            (PROGRESS_CHUNK * 2, total),
+            (total, total)
+        ]
+    );
+
+    let mut foo = alloc::vec![EvenUsize(2); total];
+    foo[PROGRESS_CHUNK + 1] = EvenUsize(3);
+    let mut calls = alloc::vec::Vec::new();
+    assert!(foo.vet_progress(|done, _| calls.push(done)).is_err());
+    assert_eq!(calls, [PROGRESS_CHUNK]);
+}
+
+#[test]
+fn checked_edit_and_promote() {
+    let mut foo = Checked::from(EvenUsize(2).vet().unwrap());
+    assert!(!foo.is_dirty());
+
+    foo.get_mut().0 = 3;
+    assert!(foo.is_dirty());
+    assert!(foo.revalidate().is_err());
+    assert!(foo.is_dirty());
+
+    foo.get_mut().0 = 4;
+    assert!(foo.revalidate().is_ok());
+    assert!(!foo.is_dirty());
+    assert_eq!(*foo.into_valid().unwrap(), EvenUsize(4));
+
+    let foo = Checked::new(EvenUsize(5));
+    assert!(foo.is_dirty());
+    assert!(foo.into_valid().is_err());
+}
+
+#[test]
+fn vet_array_indexed_first_failure() {
+    let foo = vet_array_indexed([2, 4, 5, 7].map(EvenUsize));
+    assert!(matches!(foo, Err((2, OddUsize))));
+
+    let foo = vet_array_indexed([2, 4].map(EvenUsize));
+    assert!(foo.is_ok());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn valid_vec_map_each() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4)].vet().unwrap();
+
+    let bar = foo.map_each(|e| EvenUsize(e.0 * 3)).unwrap();
+    assert_eq!(*bar, [EvenUsize(6), EvenUsize(12)]);
+
+    assert!(foo.map_each(|e| EvenUsize(e.0 + 1)).is_err());
+}
+
+#[test]
+fn vet_ref_unsized() {
+    #[repr(transparent)]
+    struct Lower(str);
+
+    impl Lower {
+        fn new(s: &str) -> &Lower {
+            // SAFETY: `Lower` is `#[repr(transparent)]` over `str`.
+            unsafe { &*(s as *const str as *const Lower) }
+        }
+    }
+
+    #[derive(Debug)]
+    struct NotLowercase;
+
+    impl Vet for Lower {
+        type Error = NotLowercase;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            if self.0.chars().any(char::is_uppercase) {
+                Err(NotLowercase)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let foo = Lower::new("hello");
+    let bar: Valid<&Lower> = foo.vet_ref().unwrap();
+    assert_eq!(&bar.into_inner().0, "hello");
+
+    assert!(Lower::new("Hello").vet_ref().is_err());
+
+    let foo = [EvenUsize(2), EvenUsize(4)];
+    let bar: Valid<&[EvenUsize]> = foo[..].vet_ref().unwrap();
+    assert_eq!(bar.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "status")]
+fn vet_status_code() {
+    #[derive(Debug)]
+    struct Unprocessable;
+
+    impl VetStatus for Unprocessable {
+        fn status_code(&self) -> u16 {
+            422
+        }
+    }
+
+    struct Body(bool);
+
+    impl Vet for Body {
+        type Error = Unprocessable;
+        fn is_valid(&self) -> Result<(), Self::Error> {
+            match self.0 {
+                true => Ok(()),
+                false => Err(Unprocessable),
+            }
+        }
+    }
+
+    assert!(Body(true).vet_status().is_ok());
+    assert!(matches!(
+        Body(false).vet_status(),
+        Err((422, Unprocessable))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipeline_named_steps() {
+    let pipeline = Pipeline::new()
+        .step("nonzero", |e: &EvenUsize| match e.0 {
+            0 => Err(OddUsize),
+            _ => Ok(()),
+        })
+        .step("multiple of four", |e: &EvenUsize| match e.0 % 4 {
+            0 => Ok(()),
+            _ => Err(OddUsize),
+        })
+        .step("below 100", |e: &EvenUsize| match e.0 < 100 {
+            true => Ok(()),
+            false => Err(OddUsize),
+        });
+
+    assert_eq!(*pipeline.run(EvenUsize(8)).unwrap(), EvenUsize(8));
+
+    let foo = pipeline.run(EvenUsize(6)).unwrap_err();
+    assert_eq!(foo.len(), 1);
+    assert_eq!(foo[0].step, "multiple of four");
+
+    let foo = pipeline.run(EvenUsize(3)).unwrap_err();
+    assert_eq!(foo[0].step, "is_valid");
+
+    let pipeline = pipeline.mode(VetMode::AllErrors);
+    let foo = pipeline.run(EvenUsize(102)).unwrap_err();
+    let steps: alloc::vec::Vec<_> = foo.iter().map(|e| &*e.step).collect();
+    assert_eq!(steps, ["multiple of four", "below 100"]);
+}
+
+#[test]
+fn vet_cell() {
+    let foo = core::cell::Cell::new(Finite(1.5f64));
+    assert!(foo.is_valid().is_ok());
+
+    foo.set(Finite(f64::NAN));
+    assert_eq!(foo.is_valid(), Err(NotFinite));
+}
+
+#[test]
+#[should_panic(expected = "three is even: OddUsize")]
+fn vet_expect_panics() {
+    EvenUsize(3).vet_expect("three is even");
+}
+
+#[test]
+fn valid_array_index() {
+    let foo = [EvenUsize(2), EvenUsize(4)].vet().unwrap();
+    assert_eq!(foo[1], EvenUsize(4));
+    assert_eq!(&foo[..1], [EvenUsize(2)]);
+}
+
+#[test]
+#[should_panic]
+fn valid_array_index_out_of_bounds() {
+    let foo = [EvenUsize(2), EvenUsize(4)].vet().unwrap();
+    let _ = &foo[core::hint::black_box(2)];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn valid_vec_index() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4)].vet().unwrap();
+    assert_eq!(foo[0], EvenUsize(2));
+    assert_eq!(&foo[1..], [EvenUsize(4)]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn valid_vec_index_out_of_bounds() {
+    let foo = alloc::vec![EvenUsize(2), EvenUsize(4)].vet().unwrap();
+    let _ = &foo[2];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn rule_set_many_records() {
+    use alloc::boxed::Box;
+
+    let limits = [("at most 10", 10), ("at most 100", 100)];
+    let rules: RuleSet<EvenUsize> = limits
+        .into_iter()
+        .map(|(name, max)| -> Rule<EvenUsize> {
+            (
+                name.into(),
+                Box::new(move |e: &EvenUsize| match e.0 <= max {
+                    true => Ok(()),
+                    false => Err(OddUsize),
+                }),
+            )
+        })
+        .collect();
+
+    assert!(rules.vet(EvenUsize(4)).is_ok());
+    assert!(rules.vet(EvenUsize(8)).is_ok());
+
+    let foo = rules.vet(EvenUsize(12)).unwrap_err();
+    assert_eq!(foo.step, "at most 10");
+
+    let foo = rules.vet(EvenUsize(5)).unwrap_err();
+    assert_eq!(foo.step, "is_valid");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn rule_set_owned_names() {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+
+    let config = String::from("at most 10=10\nat most 100=100");
+    let rules: RuleSet<EvenUsize> = config
+        .lines()
+        .map(|line| -> Rule<EvenUsize> {
+            let (name, max) = line.split_once('=').unwrap();
+            let max: usize = max.parse().unwrap();
+            (
+                String::from(name).into(),
+                Box::new(move |e: &EvenUsize| match e.0 <= max {
+                    true => Ok(()),
+                    false => Err(OddUsize),
+                }),
+            )
+        })
+        .collect();
+    drop(config);
+
+    assert!(rules.vet(EvenUsize(8)).is_ok());
+
+    let foo = rules.vet(EvenUsize(12)).unwrap_err();
+    assert_eq!(foo.step, "at most 10");
+
+    let pipeline =
+        Pipeline::new().step(String::from("at most 2"), |e: &EvenUsize| match e.0 <= 2 {
+            true => Ok(()),
+            false => Err(OddUsize),
+        });
+    let foo = pipeline.run(EvenUsize(4)).unwrap_err();
+    assert_eq!(foo[0].step, "at most 2");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn valid_box_raw_round_trip() {
+    let foo = alloc::boxed::Box::new(EvenUsize(6)).vet().unwrap();
+
+    let ptr = foo.into_raw();
+    // SAFETY: `ptr` was just returned by `into_raw`, so it points to a live
+    // allocation which nothing else owns or has freed.
+    assert_eq!(unsafe { &*ptr }, &EvenUsize(6));
+
+    // SAFETY: `ptr` came from `Valid::into_raw` and is still live and uniquely
+    // owned, as it has not yet been converted back into a box.
+    let foo = unsafe { Valid::from_raw(ptr) };
+    assert_eq!(**foo, EvenUsize(6));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_vec_all_indexed() {
+    let foo = alloc::vec![2, 4, 5, 8, 9]
+        .into_iter()
+        .map(EvenUsize)
+        .collect::<alloc::vec::Vec<_>>();
+    assert!(matches!(foo.vet_all_indexed(), Err((2, OddUsize))));
+
+    let foo = alloc::vec![EvenUsize(2)];
+    assert!(foo.vet_all_indexed().is_ok());
+}
+
+#[test]
+fn vet_parity_checked() {
+    assert!(ParityChecked::<true>(4).is_valid().is_ok());
+    assert_eq!(ParityChecked::<true>(5).is_valid(), Err(ParityError));
+
+    assert!(ParityChecked::<false>(5).is_valid().is_ok());
+    assert_eq!(ParityChecked::<false>(4).is_valid(), Err(ParityError));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vet_binary_heap() {
+    let foo = alloc::collections::BinaryHeap::from([EvenUsize(2), EvenUsize(8)]);
+    assert!(foo.is_valid().is_ok());
+
+    let foo = alloc::collections::BinaryHeap::from([EvenUsize(2), EvenUsize(7), EvenUsize(4)]);
+    assert!(foo.is_valid().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn index_by_projection() {
+    let items = alloc::vec![
+        EvenUsize(12).vet().unwrap(),
+        EvenUsize(24).vet().unwrap(),
+        EvenUsize(36).vet().unwrap(),
+    ];
+    let foo = index_by(items, |e| e.0 / 12);
+
+    assert_eq!(foo.len(), 3);
+    let bar: &Valid<EvenUsize> = &foo[&2];
+    assert_eq!(**bar, EvenUsize(24));
+    assert!(!foo.contains_key(&4));
+}
+
+#[test]
+fn vet_half_open_ranges() {
+    assert!((EvenUsize(2)..).is_valid().is_ok());
+    assert!((EvenUsize(3)..).is_valid().is_err());
+
+    assert!((..EvenUsize(4)).is_valid().is_ok());
+    assert!((..EvenUsize(5)).is_valid().is_err());
+}
+
+#[test]
+fn vet_eq_confirmation() {
+    let foo = EvenUsize(4).vet_eq(&EvenUsize(4));
+    assert_eq!(*foo.unwrap(), EvenUsize(4));
+
+    let foo = EvenUsize(4).vet_eq(&EvenUsize(6));
+    assert!(matches!(foo, Err(VetEqError::NotEqual)));
+
+    let foo = EvenUsize(3).vet_eq(&EvenUsize(3));
+    assert!(matches!(foo, Err(VetEqError::Invalid(OddUsize))));
+}